@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use scale::{Decode, Encode};
+use crate::types::Error as CoreError;
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
@@ -165,17 +166,51 @@ pub struct PipelineResult {
     pub total_duration_ms: u64,
     /// Summary report
     pub summary: String,
+    /// Coverage percentage each module must reach
+    pub coverage_threshold: u32,
+}
+
+/// Changes between two pipeline runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineDiff {
+    /// Change in total number of tests run
+    pub total_tests_delta: i64,
+    /// Change in overall pass rate, in percentage points
+    pub pass_rate_delta: f64,
+    /// Change in average coverage, in percentage points
+    pub average_coverage_delta: f64,
+    /// Modules failing now that were not failing in the previous run
+    pub newly_failing_modules: Vec<String>,
+    /// Modules present only in the current run
+    pub added_modules: Vec<String>,
+    /// Modules present only in the previous run
+    pub removed_modules: Vec<String>,
+}
+
+impl PipelineDiff {
+    /// Check if pass rate or coverage dropped, or a module started failing
+    pub fn has_regression(&self) -> bool {
+        self.pass_rate_delta < 0.0
+            || self.average_coverage_delta < 0.0
+            || !self.newly_failing_modules.is_empty()
+    }
 }
 
 impl PipelineResult {
-    /// Create new pipeline result
+    /// Create new pipeline result with the default 80% coverage threshold
     pub fn new(pipeline_name: &str) -> Self {
+        Self::with_coverage_threshold(pipeline_name, 80)
+    }
+
+    /// Create new pipeline result with a custom coverage threshold
+    pub fn with_coverage_threshold(pipeline_name: &str, coverage_threshold: u32) -> Self {
         Self {
             pipeline_name: pipeline_name.to_string(),
             success: true,
             test_results: Vec::new(),
             total_duration_ms: 0,
             summary: String::new(),
+            coverage_threshold,
         }
     }
 
@@ -185,7 +220,7 @@ impl PipelineResult {
             self.success = false;
         }
         
-        if !result.meets_coverage_threshold(80) {
+        if !result.meets_coverage_threshold(self.coverage_threshold) {
             self.success = false;
         }
         
@@ -193,23 +228,70 @@ impl PipelineResult {
         self.test_results.push(result);
     }
 
-    /// Generate summary report
-    pub fn generate_summary(&mut self) {
-        let total_tests: u32 = self.test_results.iter().map(|r| r.tests_run).sum();
+    /// Total number of tests run across all modules
+    pub fn total_tests(&self) -> u32 {
+        self.test_results.iter().map(|r| r.tests_run).sum()
+    }
+
+    /// Overall pass rate across all modules
+    pub fn pass_rate(&self) -> f64 {
+        let total_tests = self.total_tests();
         let passed_tests: u32 = self.test_results.iter().map(|r| r.tests_passed).sum();
-        let failed_tests: u32 = self.test_results.iter().map(|r| r.tests_failed).sum();
-        
-        let pass_rate = if total_tests > 0 {
+
+        if total_tests > 0 {
             (passed_tests as f64 / total_tests as f64) * 100.0
         } else {
             0.0
-        };
-        
-        let avg_coverage: f64 = if !self.test_results.is_empty() {
+        }
+    }
+
+    /// Average coverage across all modules
+    pub fn average_coverage(&self) -> f64 {
+        if !self.test_results.is_empty() {
             self.test_results.iter().map(|r| r.coverage_percentage).sum::<f64>() / self.test_results.len() as f64
         } else {
             0.0
-        };
+        }
+    }
+
+    /// Compare this run against a previous run of the pipeline
+    pub fn compare(&self, previous: &PipelineResult) -> PipelineDiff {
+        let find_previous = |name: &str| previous.test_results.iter().find(|r| r.module_name == name);
+
+        let added_modules = self.test_results.iter()
+            .filter(|r| find_previous(&r.module_name).is_none())
+            .map(|r| r.module_name.clone())
+            .collect();
+
+        let removed_modules = previous.test_results.iter()
+            .filter(|p| !self.test_results.iter().any(|r| r.module_name == p.module_name))
+            .map(|p| p.module_name.clone())
+            .collect();
+
+        // A module added in this run counts as newly failing if it fails
+        let newly_failing_modules = self.test_results.iter()
+            .filter(|r| !r.success)
+            .filter(|r| find_previous(&r.module_name).is_none_or(|p| p.success))
+            .map(|r| r.module_name.clone())
+            .collect();
+
+        PipelineDiff {
+            total_tests_delta: self.total_tests() as i64 - previous.total_tests() as i64,
+            pass_rate_delta: self.pass_rate() - previous.pass_rate(),
+            average_coverage_delta: self.average_coverage() - previous.average_coverage(),
+            newly_failing_modules,
+            added_modules,
+            removed_modules,
+        }
+    }
+
+    /// Generate summary report
+    pub fn generate_summary(&mut self) {
+        let total_tests = self.total_tests();
+        let passed_tests: u32 = self.test_results.iter().map(|r| r.tests_passed).sum();
+        let failed_tests: u32 = self.test_results.iter().map(|r| r.tests_failed).sum();
+        let pass_rate = self.pass_rate();
+        let avg_coverage = self.average_coverage();
         
         self.summary = format!(
             "Pipeline: {}\nTotal Tests: {}\nPassed: {}\nFailed: {}\nPass Rate: {:.2}%\nAverage Coverage: {:.2}%\nDuration: {}ms",
@@ -222,6 +304,68 @@ impl PipelineResult {
             self.total_duration_ms
         );
     }
+
+    /// Export results as JUnit XML, one `testsuite` per module
+    pub fn to_junit_xml(&self) -> String {
+        let total_tests: u32 = self.test_results.iter().map(|r| r.tests_run).sum();
+        let failed_tests: u32 = self.test_results.iter().map(|r| r.tests_failed).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{}\">\n",
+            xml_escape(&self.pipeline_name),
+            total_tests,
+            failed_tests,
+            junit_seconds(self.total_duration_ms)
+        ));
+
+        for result in &self.test_results {
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{}\">\n",
+                xml_escape(&result.module_name),
+                result.tests_run,
+                result.tests_failed,
+                junit_seconds(result.duration_ms)
+            ));
+            xml.push_str("    <properties>\n");
+            xml.push_str(&format!(
+                "      <property name=\"coverage\" value=\"{:.2}\"/>\n",
+                result.coverage_percentage
+            ));
+            xml.push_str("    </properties>\n");
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    /// Export results as JSON
+    #[cfg(feature = "std")]
+    pub fn to_json(&self) -> Result<String, CoreError> {
+        serde_json::to_string(self).map_err(|_| CoreError::EncodingError)
+    }
+}
+
+/// Format milliseconds as JUnit seconds
+fn junit_seconds(duration_ms: u64) -> String {
+    format!("{}.{:03}", duration_ms / 1000, duration_ms % 1000)
+}
+
+/// Escape text for use in an XML attribute
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 /// Mock test data generator for CI/CD testing
@@ -269,7 +413,7 @@ impl CiCdPipeline {
 
     /// Execute pipeline with mock test data
     pub fn execute(&self) -> PipelineResult {
-        let mut result = PipelineResult::new(&self.config.name);
+        let mut result = PipelineResult::with_coverage_threshold(&self.config.name, self.config.coverage_threshold);
         
         // Validate configuration first
         if let Err(error) = self.config.validate() {
@@ -512,6 +656,26 @@ mod tests {
         assert_eq!(pipeline_result.test_results.len(), 2);
     }
 
+    #[test]
+    fn test_adding_test_results_custom_threshold() {
+        let mut pipeline_result = PipelineResult::with_coverage_threshold("test_pipeline", 90);
+        assert_eq!(pipeline_result.coverage_threshold, 90);
+
+        pipeline_result.add_test_result(TestResult::success("module1", 10, 500, 95.0));
+        assert!(pipeline_result.success);
+
+        // Passes the default 80% threshold but not the configured 90%
+        pipeline_result.add_test_result(TestResult::success("module2", 10, 500, 85.0));
+        assert!(!pipeline_result.success);
+
+        let mut config = CiCdConfig::default();
+        config.coverage_threshold = 90;
+        let result = CiCdPipeline::new(config).execute();
+        assert_eq!(result.coverage_threshold, 90);
+        // Mock "governance_score" reports 87.5% coverage
+        assert!(!result.success);
+    }
+
     #[test]
     fn test_pipeline_summary_generation() {
         let mut pipeline_result = PipelineResult::new("test_pipeline");
@@ -528,6 +692,69 @@ mod tests {
         assert!(pipeline_result.summary.contains("25"));
     }
 
+    #[test]
+    fn test_pipeline_compare() {
+        let mut previous = PipelineResult::new("test_pipeline");
+        previous.add_test_result(TestResult::success("staking_score", 40, 1000, 90.0));
+        previous.add_test_result(TestResult::success("governance_score", 30, 800, 85.0));
+        previous.add_test_result(TestResult::success("legacy_module", 10, 200, 80.0));
+
+        let mut current = PipelineResult::new("test_pipeline");
+        current.add_test_result(TestResult::success("staking_score", 45, 1100, 90.0));
+        current.add_test_result(TestResult::failure("governance_score", 30, 3, 800));
+        current.add_test_result(TestResult::failure("new_module", 5, 5, 100));
+
+        let diff = current.compare(&previous);
+        assert_eq!(diff.total_tests_delta, 0);
+        assert_eq!(diff.added_modules, vec!["new_module".to_string()]);
+        assert_eq!(diff.removed_modules, vec!["legacy_module".to_string()]);
+        assert_eq!(diff.newly_failing_modules, vec!["governance_score".to_string(), "new_module".to_string()]);
+        // 100% -> 72/80 = 90%
+        assert!((diff.pass_rate_delta + 10.0).abs() < 1e-9);
+        // 85% -> 30%
+        assert!((diff.average_coverage_delta + 55.0).abs() < 1e-9);
+        assert!(diff.has_regression());
+
+        let unchanged = previous.compare(&previous);
+        assert_eq!(unchanged.total_tests_delta, 0);
+        assert!(unchanged.added_modules.is_empty());
+        assert!(unchanged.removed_modules.is_empty());
+        assert!(!unchanged.has_regression());
+    }
+
+    #[test]
+    fn test_junit_xml_export() {
+        let mut pipeline_result = PipelineResult::new("ci <main>");
+        for result in MockTestData::mixed_results() {
+            pipeline_result.add_test_result(result);
+        }
+
+        let xml = pipeline_result.to_junit_xml();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<testsuites name=\"ci &lt;main&gt;\" tests=\"115\" failures=\"5\" time=\"2.980\">"));
+        assert!(xml.contains("<testsuite name=\"governance_score\" tests=\"38\" failures=\"5\" time=\"0.980\">"));
+        assert!(xml.contains("<property name=\"coverage\" value=\"95.00\"/>"));
+        assert_eq!(xml.matches("<testsuite ").count(), 3);
+        assert!(xml.trim_end().ends_with("</testsuites>"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_json_export() {
+        let mut pipeline_result = PipelineResult::new("test_pipeline");
+        for result in MockTestData::successful_results() {
+            pipeline_result.add_test_result(result);
+        }
+        pipeline_result.generate_summary();
+
+        let json = pipeline_result.to_json().unwrap();
+        let parsed: PipelineResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.pipeline_name, "test_pipeline");
+        assert_eq!(parsed.test_results.len(), 4);
+        assert_eq!(parsed.test_results[1].coverage_percentage, 87.5);
+        assert_eq!(parsed.summary, pipeline_result.summary);
+    }
+
     #[test]
     fn test_mock_test_data() {
         let successful = MockTestData::successful_results();
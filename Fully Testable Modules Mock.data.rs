@@ -6,6 +6,8 @@
 use serde::{Deserialize, Serialize};
 use scale::{Decode, Encode};
 
+use crate::scoring_engine::ChainData;
+
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
@@ -417,6 +419,82 @@ impl MockCommunityData {
     }
 }
 
+/// Small deterministic xorshift64 PRNG for reproducible mock data
+///
+/// Not suitable for anything security related.
+#[derive(Debug, Clone)]
+pub struct MockRng {
+    state: u64,
+}
+
+impl MockRng {
+    /// Create a generator from a seed (a zero seed is remapped, since xorshift
+    /// would otherwise only ever produce zeros)
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+    
+    /// Next raw 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+    
+    /// Value in the inclusive range `[min, max]`
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        if max <= min {
+            return min;
+        }
+        let span = max - min;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+        min + self.next_u64() % (span + 1)
+    }
+    
+    /// Returns true with the given probability in percent
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0, 99) < percent
+    }
+    
+    /// Index into `weights`, picked with probability proportional to its weight
+    ///
+    /// Returns `None` if there are no weights or they are all zero.
+    pub fn weighted_index(&mut self, weights: &[u64]) -> Option<usize> {
+        let total = weights.iter().fold(0u64, |sum, &w| sum.saturating_add(w));
+        if total == 0 {
+            return None;
+        }
+        
+        let mut pick = self.range(0, total - 1);
+        for (i, &weight) in weights.iter().enumerate() {
+            if pick < weight {
+                return Some(i);
+            }
+            pick -= weight;
+        }
+        
+        // Only reachable if the total saturated
+        weights.iter().rposition(|&w| w > 0)
+    }
+}
+
+/// Start of generated governance timelines (2023-01-01 00:00:00 UTC)
+const TIMELINE_START: u64 = 1672531200;
+
+/// Gap between consecutive timeline events, in seconds (one hour to one week)
+const TIMELINE_MIN_GAP: u64 = 3600;
+const TIMELINE_MAX_GAP: u64 = 604800;
+
+/// Relative odds of a vote, a rejected proposal and an approved proposal
+const TIMELINE_EVENT_WEIGHTS: [u64; 3] = [85, 10, 5];
+
 /// Mock data generator for comprehensive testing
 pub struct MockDataGenerator;
 
@@ -439,6 +517,29 @@ impl MockDataGenerator {
         accounts
     }
     
+    /// Generate a set of mock accounts with seeded pseudo-random values
+    ///
+    /// Reputation scores fall in 0-100, creation times between 2020-01-01 and
+    /// 2023-11-08, and roughly 80% of accounts are active. The same seed always
+    /// reproduces the same accounts.
+    pub fn generate_accounts_seeded(count: usize, seed: u64) -> Vec<MockAccount> {
+        let mut rng = MockRng::new(seed);
+        let mut accounts = Vec::with_capacity(count);
+        
+        for i in 0..count {
+            accounts.push(MockAccount {
+                id: i as u32,
+                address: format!("5G{:016x}", rng.next_u64()),
+                name: format!("User{}", i),
+                reputation_score: rng.range(0, 100),
+                created_at: rng.range(1577836800, 1699430400),
+                is_active: rng.chance(80),
+            });
+        }
+        
+        accounts
+    }
+    
     /// Generate mock staking data
     pub fn generate_stake_data(account_ids: &[u32]) -> Vec<MockStakeData> {
         let mut stakes = Vec::new();
@@ -476,6 +577,47 @@ impl MockDataGenerator {
         activities
     }
     
+    /// Generate a seeded governance history for one account
+    ///
+    /// Each entry is a cumulative snapshot taken right after an event, paired
+    /// with the event timestamp. Timestamps start at 2023-01-01 and strictly
+    /// increase; counts and voting power never decrease. Events are mostly
+    /// votes, with occasional (sometimes approved) proposals.
+    pub fn generate_governance_timeline(account_id: u32, events: usize, seed: u64) -> Vec<(u64, MockGovernanceActivity)> {
+        let mut rng = MockRng::new(seed);
+        let mut timeline = Vec::with_capacity(events);
+        let mut timestamp = TIMELINE_START;
+        let mut activity = MockGovernanceActivity {
+            votes_count: 0,
+            proposals_count: 0,
+            proposals_approved: 0,
+            voting_power_used: 0,
+            last_activity: TIMELINE_START,
+            ..MockGovernanceActivity::active_participant(account_id)
+        };
+        
+        for _ in 0..events {
+            timestamp += rng.range(TIMELINE_MIN_GAP, TIMELINE_MAX_GAP);
+            
+            match rng.weighted_index(&TIMELINE_EVENT_WEIGHTS) {
+                Some(0) => {
+                    activity.votes_count += 1;
+                    activity.voting_power_used += rng.range(10, 500);
+                }
+                Some(1) => activity.proposals_count += 1,
+                _ => {
+                    activity.proposals_count += 1;
+                    activity.proposals_approved += 1;
+                }
+            }
+            activity.last_activity = timestamp;
+            
+            timeline.push((timestamp, activity.clone()));
+        }
+        
+        timeline
+    }
+    
     /// Generate mock identity data
     pub fn generate_identity_data(account_ids: &[u32]) -> Vec<MockIdentityData> {
         let mut identities = Vec::new();
@@ -525,6 +667,44 @@ impl MockDataGenerator {
             community_data: Self::generate_community_data(&account_ids),
         }
     }
+    
+    /// Generate a dataset of boundary scenarios for fuzz-style tests
+    ///
+    /// Included edge cases, by account ID:
+    /// - `0`: zero stake (amount and duration both 0)
+    /// - `1`: `u64::MAX` reputation score, to exercise overflow in aggregation
+    /// - `2`: empty name and empty address
+    /// - `3`: `created_at` in the future (2100-01-01), i.e. negative account age
+    /// - `4`: duplicate ID shared by two accounts, each with its own
+    ///   stake/governance/identity/community entries
+    pub fn generate_edge_cases() -> MockDataset {
+        let accounts = vec![
+            MockAccount::new(0, "5GZeroStake", "ZeroStake"),
+            MockAccount {
+                reputation_score: u64::MAX,
+                ..MockAccount::new(1, "5GMaxReputation", "MaxReputation")
+            },
+            MockAccount::new(2, "", ""),
+            MockAccount::with_creation_time(3, "5GFutureAccount", "FutureAccount", 4102444800),
+            MockAccount::new(4, "5GDuplicateA", "DuplicateA"),
+            MockAccount::new(4, "5GDuplicateB", "DuplicateB"),
+        ];
+        let account_ids: Vec<u32> = accounts.iter().map(|a| a.id).collect();
+        
+        let mut stake_data: Vec<MockStakeData> = account_ids.iter()
+            .map(|&id| MockStakeData::valid(id))
+            .collect();
+        stake_data[0].amount = 0;
+        stake_data[0].duration = 0;
+        
+        MockDataset {
+            accounts,
+            stake_data,
+            governance_activities: Self::generate_governance_activity(&account_ids),
+            identity_data: Self::generate_identity_data(&account_ids),
+            community_data: Self::generate_community_data(&account_ids),
+        }
+    }
 }
 
 /// Complete mock dataset for testing
@@ -571,6 +751,90 @@ impl MockDataset {
     pub fn get_community_data(&self, account_id: u32) -> Option<&MockCommunityData> {
         self.community_data.iter().find(|c| c.account_id == account_id)
     }
+    
+    /// Fold all mock records for an account into the `ChainData` consumed by the scoring engine
+    ///
+    /// Missing collections contribute zeros, inactive stakes count as no stake, and the
+    /// timestamp is the latest governance/community activity (or the creation time).
+    pub fn to_chain_data(&self, account_id: u32) -> Option<ChainData> {
+        let account = self.get_account(account_id)?;
+        let stake = self.get_stake_data(account_id).filter(|s| s.is_active);
+        let governance = self.get_governance_activity(account_id);
+        let identity = self.get_identity_data(account_id);
+        let community = self.get_community_data(account_id);
+        
+        let timestamp = governance.map(|g| g.last_activity)
+            .into_iter()
+            .chain(community.map(|c| c.last_activity))
+            .max()
+            .filter(|&t| t > 0)
+            .unwrap_or(account.created_at);
+        
+        Some(ChainData {
+            account_id: account.address.clone(),
+            governance_votes: governance.map_or(0, |g| g.votes_count),
+            governance_proposals: governance.map_or(0, |g| g.proposals_count),
+            governance_proposals_approved: governance.map_or(0, |g| g.proposals_approved),
+            staking_amount: stake.map_or(0, |s| s.amount),
+            staking_duration: stake.map_or(0, |s| s.duration),
+            identity_verified: identity.is_some_and(|i| i.is_verified),
+            identity_judgements: identity.map_or(0, |i| i.judgements_count),
+            identity_judgement_levels: Vec::new(),
+            community_posts: community.map_or(0, |c| c.posts_count),
+            community_upvotes: community.map_or(0, |c| c.upvotes_received),
+            community_downvotes: community.map_or(0, |c| c.downvotes_received),
+            timestamp,
+        })
+    }
+    
+    /// Check cross-collection invariants
+    ///
+    /// Reports duplicate account IDs and any stake, governance, identity or
+    /// community entry whose account ID has no matching account.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        
+        let mut account_counts: HashMap<u32, usize> = HashMap::new();
+        for account in &self.accounts {
+            *account_counts.entry(account.id).or_insert(0) += 1;
+        }
+        
+        let mut duplicates: Vec<u32> = account_counts.iter()
+            .filter(|(_, &count)| count > 1)
+            .map(|(&id, _)| id)
+            .collect();
+        duplicates.sort();
+        for id in duplicates {
+            errors.push(format!("duplicate account id {}", id));
+        }
+        
+        let mut check_dangling = |collection: &str, ids: &mut dyn Iterator<Item = u32>| {
+            for id in ids {
+                if !account_counts.contains_key(&id) {
+                    errors.push(format!("{} entry references unknown account id {}", collection, id));
+                }
+            }
+        };
+        
+        check_dangling("stake", &mut self.stake_data.iter().map(|s| s.account_id));
+        check_dangling("governance", &mut self.governance_activities.iter().map(|g| g.account_id));
+        check_dangling("identity", &mut self.identity_data.iter().map(|i| i.account_id));
+        check_dangling("community", &mut self.community_data.iter().map(|c| c.account_id));
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl From<&MockDataset> for Vec<ChainData> {
+    fn from(dataset: &MockDataset) -> Self {
+        dataset.accounts.iter()
+            .filter_map(|account| dataset.to_chain_data(account.id))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -697,6 +961,101 @@ mod tests {
         assert!(inactive_count > 0);
     }
 
+    #[test]
+    fn test_seeded_generation_is_reproducible() {
+        let first = MockDataGenerator::generate_accounts_seeded(50, 42);
+        let second = MockDataGenerator::generate_accounts_seeded(50, 42);
+        let other = MockDataGenerator::generate_accounts_seeded(50, 7);
+        
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_seeded_generation_ranges() {
+        let accounts = MockDataGenerator::generate_accounts_seeded(200, 1234);
+        assert_eq!(accounts.len(), 200);
+        
+        for account in &accounts {
+            assert!(account.reputation_score <= 100);
+            assert!(account.created_at >= 1577836800 && account.created_at <= 1699430400);
+        }
+        
+        // Values should actually vary
+        let active = accounts.iter().filter(|a| a.is_active).count();
+        assert!(active > 100 && active < 200);
+        assert!(accounts.iter().any(|a| a.reputation_score != accounts[0].reputation_score));
+    }
+
+    #[test]
+    fn test_mock_rng_zero_seed() {
+        let mut rng = MockRng::new(0);
+        assert_ne!(rng.next_u64(), 0);
+        assert_eq!(rng.range(5, 5), 5);
+    }
+
+    #[test]
+    fn test_mock_rng_weighted_index() {
+        let mut rng = MockRng::new(99);
+        assert_eq!(rng.weighted_index(&[]), None);
+        assert_eq!(rng.weighted_index(&[0, 0]), None);
+        assert_eq!(rng.weighted_index(&[0, 5, 0]), Some(1));
+        assert_eq!(rng.weighted_index(&[u64::MAX, u64::MAX]).map(|i| i < 2), Some(true));
+        
+        let mut counts = [0usize; 2];
+        for _ in 0..1000 {
+            counts[rng.weighted_index(&[9, 1]).unwrap()] += 1;
+        }
+        assert!(counts[0] > 800 && counts[1] > 30);
+    }
+
+    #[test]
+    fn test_governance_timeline() {
+        let timeline = MockDataGenerator::generate_governance_timeline(7, 200, 42);
+        assert_eq!(timeline.len(), 200);
+        assert_eq!(timeline, MockDataGenerator::generate_governance_timeline(7, 200, 42));
+        assert!(MockDataGenerator::generate_governance_timeline(7, 0, 42).is_empty());
+        
+        for pair in timeline.windows(2) {
+            let (prev_time, prev) = &pair[0];
+            let (time, activity) = &pair[1];
+            assert!(time > prev_time);
+            assert!(activity.votes_count >= prev.votes_count);
+            assert!(activity.proposals_count >= prev.proposals_count);
+            assert!(activity.voting_power_used >= prev.voting_power_used);
+        }
+        
+        for (time, activity) in &timeline {
+            assert_eq!(activity.account_id, 7);
+            assert_eq!(activity.last_activity, *time);
+            assert!(activity.proposals_approved <= activity.proposals_count);
+        }
+        
+        let (_, last) = timeline.last().unwrap();
+        assert_eq!(last.votes_count + last.proposals_count, 200);
+        assert!(last.votes_count > last.proposals_count);
+    }
+
+    #[test]
+    fn test_edge_case_dataset() {
+        let dataset = MockDataGenerator::generate_edge_cases();
+        
+        assert_eq!(dataset.get_stake_data(0).unwrap().amount, 0);
+        assert_eq!(dataset.get_account(1).unwrap().reputation_score, u64::MAX);
+        assert!(dataset.get_account(2).unwrap().name.is_empty());
+        assert!(dataset.get_account(3).unwrap().created_at > 1699430400);
+        assert_eq!(dataset.accounts.iter().filter(|a| a.id == 4).count(), 2);
+        
+        // Every account entry still has a matching entry in the other collections
+        assert_eq!(dataset.stake_data.len(), dataset.accounts.len());
+        assert_eq!(dataset.governance_activities.len(), dataset.accounts.len());
+        assert_eq!(dataset.identity_data.len(), dataset.accounts.len());
+        assert_eq!(dataset.community_data.len(), dataset.accounts.len());
+        
+        // Duplicate IDs are deliberate, so the dataset is not internally consistent
+        assert_eq!(dataset.validate(), Err(vec!["duplicate account id 4".to_string()]));
+    }
+
     #[test]
     fn test_stake_data_generation() {
         let account_ids = vec![1, 2, 3, 4, 5];
@@ -799,6 +1158,54 @@ mod tests {
         assert_eq!(community.unwrap().account_id, 1);
     }
 
+    #[test]
+    fn test_dataset_validate() {
+        let dataset = MockDataGenerator::generate_complete_dataset(8);
+        assert!(dataset.validate().is_ok());
+    }
+
+    #[test]
+    fn test_dataset_validate_detects_corruption() {
+        let mut dataset = MockDataGenerator::generate_complete_dataset(4);
+        dataset.stake_data[0].account_id = 999;
+        dataset.community_data[1].account_id = 1000;
+        dataset.accounts[3].id = 2;
+        
+        let errors = dataset.validate().unwrap_err();
+        assert!(!errors.is_empty());
+        assert!(errors.iter().any(|e| e == "duplicate account id 2"));
+        assert!(errors.iter().any(|e| e.contains("stake") && e.contains("999")));
+        assert!(errors.iter().any(|e| e.contains("community") && e.contains("1000")));
+    }
+
+    #[test]
+    fn test_to_chain_data() {
+        let dataset = MockDataGenerator::generate_complete_dataset(4);
+        
+        // Account 0 gets the first variant of every generator
+        let data = dataset.to_chain_data(0).unwrap();
+        assert_eq!(data.account_id, "5G0");
+        assert_eq!(data.governance_votes, 500);
+        assert_eq!(data.staking_amount, 10_000_000_000_000);
+        assert!(data.identity_verified);
+        assert_eq!(data.identity_judgements, 5);
+        assert_eq!(data.community_posts, 500);
+        assert_eq!(data.timestamp, 1699430400);
+        
+        assert!(dataset.to_chain_data(99).is_none());
+    }
+
+    #[test]
+    fn test_dataset_into_chain_data() {
+        let dataset = MockDataGenerator::generate_complete_dataset(6);
+        let records: Vec<ChainData> = (&dataset).into();
+        
+        assert_eq!(records.len(), 6);
+        
+        let mut engine = crate::scoring_engine::ScoringEngine::new(Default::default());
+        assert!(engine.calculate_batch_scores(records).iter().all(|r| r.is_ok()));
+    }
+
     #[test]
     fn test_edge_cases() {
         // Test account with empty name
@@ -9,27 +9,268 @@
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap, collections::BTreeSet as HashSet,
+            collections::VecDeque, format};
 
 #[cfg(feature = "std")]
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JudgementLevel {
+    Reasonable,
+    KnownGood,
+    OutOfDate,
+    LowQuality,
+    Erroneous,
+}
+
+impl JudgementLevel {
+    pub fn weight(&self) -> f64 {
+        match self {
+            JudgementLevel::KnownGood => 20.0,
+            JudgementLevel::Reasonable => 10.0,
+            JudgementLevel::OutOfDate => 2.0,
+            JudgementLevel::LowQuality => 0.0,
+            JudgementLevel::Erroneous => -10.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainData {
     pub account_id: String,
     pub governance_votes: u32,
     pub governance_proposals: u32,
+    /// Subset of `governance_proposals` that were approved.
+    #[serde(default)]
+    pub governance_proposals_approved: u32,
     pub staking_amount: u64,
     pub staking_duration: u64,
     pub identity_verified: bool,
     pub identity_judgements: u32,
+    #[serde(default)]
+    pub identity_judgement_levels: Vec<JudgementLevel>,
+    pub community_posts: u32,
+    pub community_upvotes: u32,
+    #[serde(default)]
+    pub community_downvotes: u32,
+    pub timestamp: u64,
+}
+
+impl ChainData {
+    /// Count of discrete on-chain actions: votes, proposals, identity judgements and posts.
+    pub fn total_actions(&self) -> u64 {
+        self.governance_votes as u64
+            + self.governance_proposals as u64
+            + self.identity_judgements as u64
+            + self.community_posts as u64
+    }
+
+    /// Projects onto `dotrepute_core::scoring::MetricData`, dropping
+    /// `account_id`, `governance_proposals_approved`, `identity_judgement_levels`,
+    /// `community_downvotes` and `timestamp`.
+    #[cfg(feature = "dotrepute-core")]
+    pub fn to_metric_data(&self) -> dotrepute_core::scoring::MetricData {
+        dotrepute_core::scoring::MetricData {
+            governance_votes: self.governance_votes,
+            governance_proposals: self.governance_proposals,
+            staking_amount: self.staking_amount,
+            staking_duration: self.staking_duration,
+            identity_verified: self.identity_verified,
+            identity_judgements: self.identity_judgements,
+            community_posts: self.community_posts,
+            community_upvotes: self.community_upvotes,
+        }
+    }
+}
+
+/// Lifts `dotrepute_core` input into engine input. Fields MetricData lacks are
+/// defaulted: empty `account_id`, no approved proposals, no
+/// `identity_judgement_levels` (judgements then score at the flat rate), no
+/// `community_downvotes` and `timestamp` 0.
+#[cfg(feature = "dotrepute-core")]
+impl From<dotrepute_core::scoring::MetricData> for ChainData {
+    fn from(data: dotrepute_core::scoring::MetricData) -> Self {
+        ChainData {
+            account_id: String::new(),
+            governance_votes: data.governance_votes,
+            governance_proposals: data.governance_proposals,
+            governance_proposals_approved: 0,
+            staking_amount: data.staking_amount,
+            staking_duration: data.staking_duration,
+            identity_verified: data.identity_verified,
+            identity_judgements: data.identity_judgements,
+            identity_judgement_levels: Vec::new(),
+            community_posts: data.community_posts,
+            community_upvotes: data.community_upvotes,
+            community_downvotes: 0,
+            timestamp: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChainDataDelta {
+    pub governance_votes: u32,
+    pub governance_proposals: u32,
+    #[serde(default)]
+    pub governance_proposals_approved: u32,
+    pub staking_amount: i64,
+    pub staking_duration: u64,
+    pub identity_verified: Option<bool>,
+    pub identity_judgements: u32,
     pub community_posts: u32,
     pub community_upvotes: u32,
+    #[serde(default)]
+    pub community_downvotes: u32,
     pub timestamp: u64,
 }
 
+impl ChainDataDelta {
+    pub fn apply_to(&self, data: &mut ChainData) {
+        data.governance_votes = data.governance_votes.saturating_add(self.governance_votes);
+        data.governance_proposals = data.governance_proposals.saturating_add(self.governance_proposals);
+        data.governance_proposals_approved =
+            data.governance_proposals_approved.saturating_add(self.governance_proposals_approved);
+        data.staking_amount = if self.staking_amount >= 0 {
+            data.staking_amount.saturating_add(self.staking_amount as u64)
+        } else {
+            data.staking_amount.saturating_sub(self.staking_amount.unsigned_abs())
+        };
+        data.staking_duration = data.staking_duration.saturating_add(self.staking_duration);
+        if let Some(verified) = self.identity_verified {
+            data.identity_verified = verified;
+        }
+        data.identity_judgements = data.identity_judgements.saturating_add(self.identity_judgements);
+        data.community_posts = data.community_posts.saturating_add(self.community_posts);
+        data.community_upvotes = data.community_upvotes.saturating_add(self.community_upvotes);
+        data.community_downvotes = data.community_downvotes.saturating_add(self.community_downvotes);
+        if self.timestamp > 0 {
+            data.timestamp = self.timestamp;
+        }
+    }
+
+    fn touches_governance(&self) -> bool {
+        self.governance_votes > 0 || self.governance_proposals > 0 || self.governance_proposals_approved > 0
+    }
+
+    fn touches_staking(&self) -> bool {
+        self.staking_amount != 0 || self.staking_duration > 0
+    }
+
+    fn touches_identity(&self) -> bool {
+        self.identity_verified.is_some() || self.identity_judgements > 0
+    }
+
+    fn touches_community(&self) -> bool {
+        self.community_posts > 0 || self.community_upvotes > 0 || self.community_downvotes > 0
+    }
+}
+
+/// A field that differs between two snapshots of one account. Values are
+/// widened to u64; `identity_verified` is 0 or 1, and
+/// `identity_judgement_levels` is compared by count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDelta {
+    pub field: &'static str,
+    pub old: u64,
+    pub new: u64,
+}
+
+impl FieldDelta {
+    pub fn delta(&self) -> i128 {
+        self.new as i128 - self.old as i128
+    }
+}
+
+/// Difference for one account between two `ChainData` snapshots.
+#[derive(Debug, Clone)]
+pub enum ChainDataChange {
+    /// Account present only in the new snapshot.
+    Added(ChainData),
+    /// Account ID present only in the old snapshot.
+    Removed(String),
+    /// Account present in both with at least one differing field.
+    /// `judgement_levels_changed` is set whenever the judgement levels differ,
+    /// including changes that keep their count (and so have no `FieldDelta`).
+    Modified { account_id: String, deltas: Vec<FieldDelta>, judgement_levels_changed: bool },
+}
+
+/// Fields of `new` that differ from `old`, in declaration order.
+fn field_deltas(old: &ChainData, new: &ChainData) -> Vec<FieldDelta> {
+    let fields: [(&'static str, u64, u64); 12] = [
+        ("governance_votes", old.governance_votes as u64, new.governance_votes as u64),
+        ("governance_proposals", old.governance_proposals as u64, new.governance_proposals as u64),
+        ("governance_proposals_approved", old.governance_proposals_approved as u64,
+            new.governance_proposals_approved as u64),
+        ("staking_amount", old.staking_amount, new.staking_amount),
+        ("staking_duration", old.staking_duration, new.staking_duration),
+        ("identity_verified", old.identity_verified as u64, new.identity_verified as u64),
+        ("identity_judgements", old.identity_judgements as u64, new.identity_judgements as u64),
+        ("community_posts", old.community_posts as u64, new.community_posts as u64),
+        ("community_upvotes", old.community_upvotes as u64, new.community_upvotes as u64),
+        ("community_downvotes", old.community_downvotes as u64, new.community_downvotes as u64),
+        ("timestamp", old.timestamp, new.timestamp),
+        ("identity_judgement_levels", old.identity_judgement_levels.len() as u64,
+            new.identity_judgement_levels.len() as u64),
+    ];
+
+    fields.iter()
+        .filter(|&&(_, old_value, new_value)| old_value != new_value)
+        .map(|&(field, old, new)| FieldDelta { field, old, new })
+        .collect()
+}
+
+/// Compares two snapshots keyed by `account_id`. Changes are listed in the
+/// order accounts appear in `new`, followed by removed accounts in the order
+/// they appear in `old`; unchanged accounts are omitted. If an account
+/// appears more than once in a snapshot, its last record is used.
+pub fn chaindata_diff(old: &[ChainData], new: &[ChainData]) -> Vec<ChainDataChange> {
+    let old_by_id: HashMap<&str, &ChainData> = old.iter()
+        .map(|data| (data.account_id.as_str(), data))
+        .collect();
+    let new_by_id: HashMap<&str, &ChainData> = new.iter()
+        .map(|data| (data.account_id.as_str(), data))
+        .collect();
+
+    let mut changes = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+
+    for data in new {
+        let account_id = data.account_id.as_str();
+        if !seen.insert(account_id) {
+            continue;
+        }
+        let latest = new_by_id[account_id];
+        match old_by_id.get(account_id) {
+            None => changes.push(ChainDataChange::Added(latest.clone())),
+            Some(previous) => {
+                let deltas = field_deltas(previous, latest);
+                let judgement_levels_changed =
+                    previous.identity_judgement_levels != latest.identity_judgement_levels;
+                if !deltas.is_empty() || judgement_levels_changed {
+                    changes.push(ChainDataChange::Modified {
+                        account_id: account_id.into(),
+                        deltas,
+                        judgement_levels_changed,
+                    });
+                }
+            }
+        }
+    }
+
+    for data in old {
+        let account_id = data.account_id.as_str();
+        if !new_by_id.contains_key(account_id) && seen.insert(account_id) {
+            changes.push(ChainDataChange::Removed(account_id.into()));
+        }
+    }
+
+    changes
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoreResult {
     pub account_id: String,
@@ -40,6 +281,9 @@ pub struct ScoreResult {
     pub community_score: f64,
     pub timestamp: u64,
     pub breakdown: ScoreBreakdown,
+    /// Total score in units of `FIXED_POINT_SCALE`, set only in fixed-point mode.
+    #[serde(default)]
+    pub fixed_score: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,7 +296,101 @@ pub struct ScoreBreakdown {
     pub negative_adjustments: f64,
 }
 
-#[derive(Debug, Clone)]
+/// Points awarded per governance action. Vote points are capped at 50, and
+/// proposal plus approval points share a separate cap of 50.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GovernanceScoringParams {
+    pub vote_points: f64,
+    pub proposal_points: f64,
+    /// Extra points per approved proposal, on top of `proposal_points`.
+    pub approved_proposal_bonus: f64,
+}
+
+impl Default for GovernanceScoringParams {
+    fn default() -> Self {
+        Self {
+            vote_points: 2.0,
+            proposal_points: 5.0,
+            approved_proposal_bonus: 5.0,
+        }
+    }
+}
+
+/// Raw metric output treated as a full score. Each metric's output is
+/// rescaled to `raw / max * 100` and clamped to 0-100 before weighting, so a
+/// metric at its max contributes exactly `weight * 100` to the total. The
+/// defaults are the built-in metrics' own ceilings, which leaves their
+/// scores unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricMaxima {
+    pub governance: f64,
+    pub staking: f64,
+    pub identity: f64,
+    pub community: f64,
+}
+
+impl Default for MetricMaxima {
+    fn default() -> Self {
+        Self {
+            governance: 100.0,
+            staking: 100.0,
+            identity: 100.0,
+            community: 100.0,
+        }
+    }
+}
+
+impl MetricMaxima {
+    /// Max for the metric with the given `ScoreMetric::get_name`; metrics
+    /// without a configurable max are measured against 100.
+    pub fn for_metric(&self, name: &str) -> f64 {
+        match name {
+            "governance" => self.governance,
+            "staking" => self.staking,
+            "identity" => self.identity,
+            "community" => self.community,
+            _ => 100.0,
+        }
+    }
+}
+
+/// Rescales a raw metric output to 0-100 against `max`; a non-positive or
+/// NaN max, or a NaN raw value, yields 0.
+fn normalize_metric(raw: f64, max: f64) -> f64 {
+    if max > 0.0 && !raw.is_nan() {
+        (raw / max * 100.0).clamp(0.0, 100.0)
+    } else {
+        0.0
+    }
+}
+
+/// Leading "name: score/100" part of a metric explanation, showing the
+/// normalized score the engine weights; the raw value is added when a
+/// non-default max rescaled it.
+fn explain_score(name: &str, raw: f64, max: f64) -> String {
+    let normalized = normalize_metric(raw, max);
+    if max == 100.0 {
+        format!("{}: {:.2}/100", name, normalized)
+    } else {
+        format!("{}: {:.2}/100 [raw {:.2} of max {}]", name, normalized, raw, max)
+    }
+}
+
+/// Fixed-point counterpart of `normalize_metric`, in `FIXED_POINT_SCALE` units.
+fn normalize_metric_fixed(raw: u64, max: f64) -> u64 {
+    let max = to_fixed(max);
+    if max == 0 {
+        return 0;
+    }
+    let hundred = 100 * FIXED_POINT_SCALE;
+    (raw as u128 * hundred as u128 / max as u128).min(hundred as u128) as u64
+}
+
+/// Fields missing from a serialized config take their `Default` values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ScoringConfig {
     pub governance_weight: f64,
     pub staking_weight: f64,
@@ -63,6 +401,18 @@ pub struct ScoringConfig {
     pub negative_scoring_enabled: bool,
     pub min_score: f64,
     pub max_score: f64,
+    /// Records with fewer `ChainData::total_actions` are rejected with
+    /// `"insufficient_activity"`; 0 scores every record.
+    pub min_total_actions: u64,
+    /// Score with integer arithmetic instead of f64 so results are identical
+    /// on every platform (e.g. on-chain). Time decay then counts whole days only.
+    pub fixed_point: bool,
+    pub governance_params: GovernanceScoringParams,
+    /// Per-metric normalization applied before weighting. `total_score` is
+    /// the weighted sum of the normalized (0-100) metric scores, so with
+    /// weights summing to 1 each weight is that metric's share of a perfect
+    /// score.
+    pub metric_max: MetricMaxima,
 }
 
 impl Default for ScoringConfig {
@@ -77,23 +427,213 @@ impl Default for ScoringConfig {
             negative_scoring_enabled: true,
             min_score: 0.0,
             max_score: 100.0,
+            min_total_actions: 0,
+            fixed_point: false,
+            governance_params: GovernanceScoringParams::default(),
+            metric_max: MetricMaxima::default(),
+        }
+    }
+}
+
+/// Names accepted by `ScoringConfig::preset`.
+const PRESET_NAMES: &[&str] = &["balanced", "governance-focused", "staking-focused"];
+
+impl ScoringConfig {
+    /// Curated weight profile by name; other settings keep their defaults.
+    ///
+    /// - `balanced`: every domain counts equally.
+    /// - `governance-focused`: rewards voting and proposing, for DAOs and
+    ///   councils that care most about civic participation.
+    /// - `staking-focused`: rewards capital at risk over time, for
+    ///   validator/nominator selection and economic-security use cases.
+    ///
+    /// Returns `None` for unknown names. Weights of every preset sum to 1.
+    pub fn preset(name: &str) -> Option<Self> {
+        let (governance, staking, identity, community) = match name {
+            "balanced" => (0.25, 0.25, 0.25, 0.25),
+            "governance-focused" => (0.5, 0.2, 0.15, 0.15),
+            "staking-focused" => (0.2, 0.5, 0.15, 0.15),
+            _ => return None,
+        };
+        Some(Self {
+            governance_weight: governance,
+            staking_weight: staking,
+            identity_weight: identity,
+            community_weight: community,
+            ..Self::default()
+        })
+    }
+
+    /// Names of all presets, e.g. for populating a UI selector.
+    pub fn preset_names() -> &'static [&'static str] {
+        PRESET_NAMES
+    }
+
+    /// Checks that the config describes a usable scoring setup.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let weights = [self.governance_weight, self.staking_weight,
+                       self.identity_weight, self.community_weight];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err("Weights must be finite and non-negative");
+        }
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return Err("At least one weight must be positive");
+        }
+        if !(self.time_decay_rate > 0.0 && self.time_decay_rate <= 1.0) {
+            return Err("Time decay rate must be in (0, 1]");
+        }
+        if !self.min_score.is_finite() || !self.max_score.is_finite() || self.min_score > self.max_score {
+            return Err("Score bounds must be finite with min_score <= max_score");
+        }
+        let params = &self.governance_params;
+        if [params.vote_points, params.proposal_points, params.approved_proposal_bonus]
+            .iter()
+            .any(|p| !p.is_finite() || *p < 0.0)
+        {
+            return Err("Governance points must be finite and non-negative");
+        }
+        let max = &self.metric_max;
+        if [max.governance, max.staking, max.identity, max.community]
+            .iter()
+            .any(|m| !m.is_finite() || *m <= 0.0)
+        {
+            return Err("Metric maxima must be finite and positive");
+        }
+        Ok(())
+    }
+
+    /// Parses and validates a JSON config.
+    #[cfg(feature = "std")]
+    pub fn from_json(s: &str) -> Result<Self, &'static str> {
+        let config: Self = serde_json::from_str(s).map_err(|_| "JSON parsing failed")?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses and validates a TOML config.
+    #[cfg(feature = "std")]
+    pub fn from_toml(s: &str) -> Result<Self, &'static str> {
+        let config: Self = toml::from_str(s).map_err(|_| "TOML parsing failed")?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Units per score point in fixed-point mode (`ScoringConfig::fixed_point`).
+pub const FIXED_POINT_SCALE: u64 = 1_000_000;
+
+/// ln(2) * 10^12, used to turn an integer log2 into ln.
+const LN_2_E12: u128 = 693_147_180_560;
+
+/// Converts a config value to fixed-point units, rounding to nearest;
+/// negative and NaN values become 0.
+fn to_fixed(value: f64) -> u64 {
+    if value > 0.0 {
+        (value * FIXED_POINT_SCALE as f64 + 0.5) as u64
+    } else {
+        0
+    }
+}
+
+/// ln(x) in fixed-point units for x >= 1, via a bit-by-bit binary logarithm.
+fn fixed_ln(x: u64) -> u64 {
+    const FRAC_BITS: u32 = 32;
+    const ONE: u128 = 1 << 62;
+
+    let int_part = 63 - x.leading_zeros();
+    // Mantissa x / 2^int_part in [1, 2), as a Q62 number
+    let mut y = if int_part <= 62 {
+        (x as u128) << (62 - int_part)
+    } else {
+        (x as u128) >> (int_part - 62)
+    };
+
+    let mut frac: u128 = 0;
+    for bit in (0..FRAC_BITS).rev() {
+        y = (y * y) >> 62;
+        if y >= 2 * ONE {
+            y >>= 1;
+            frac |= 1 << bit;
+        }
+    }
+
+    let log2 = ((int_part as u128) << FRAC_BITS) | frac;
+    ((log2 * LN_2_E12) >> FRAC_BITS) as u64 / (1_000_000_000_000 / FIXED_POINT_SCALE)
+}
+
+/// Integer square root (floor).
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// base^exp with base in fixed-point units; base is capped at 1.0 so the
+/// result never grows.
+fn fixed_pow(base: u64, mut exp: u64) -> u64 {
+    let scale = FIXED_POINT_SCALE as u128;
+    let mut base = (base as u128).min(scale);
+    let mut result = scale;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base / scale;
         }
+        base = base * base / scale;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Returns `value` if it lies within `[lo, hi]`, otherwise `error`.
+///
+/// Follows `dotrepute_core::validation::in_range`, including rejecting
+/// `lo > hi`, so `validate_data` bounds do not need the optional core
+/// dependency.
+fn in_range<T: PartialOrd>(value: T, lo: T, hi: T, error: &'static str) -> Result<T, &'static str> {
+    if lo > hi {
+        return Err("Invalid range bounds");
+    }
+    if value >= lo && value <= hi {
+        Ok(value)
+    } else {
+        Err(error)
     }
 }
 
-pub trait ScoreMetric {
+pub trait ScoreMetric: Send + Sync {
     fn calculate(&self, data: &ChainData, config: &ScoringConfig) -> f64;
     fn get_weight(&self, config: &ScoringConfig) -> f64;
     fn get_name(&self) -> &'static str;
     fn validate_data(&self, data: &ChainData) -> Result<(), &'static str>;
+
+    /// One line describing how `calculate` arrived at its score, leading
+    /// with the score normalized against `config.metric_max`.
+    fn explain(&self, data: &ChainData, config: &ScoringConfig) -> String {
+        let name = self.get_name();
+        explain_score(name, self.calculate(data, config), config.metric_max.for_metric(name))
+    }
 }
 
 pub struct GovernanceScoreMetric;
 
 impl ScoreMetric for GovernanceScoreMetric {
-    fn calculate(&self, data: &ChainData, _config: &ScoringConfig) -> f64 {
-        let vote_score = (data.governance_votes as f64 * 2.0).min(50.0);
-        let proposal_score = (data.governance_proposals as f64 * 5.0).min(50.0);
+    fn calculate(&self, data: &ChainData, config: &ScoringConfig) -> f64 {
+        let params = &config.governance_params;
+        let vote_score = (data.governance_votes as f64 * params.vote_points).min(50.0);
+        let proposal_score = (data.governance_proposals as f64 * params.proposal_points
+            + data.governance_proposals_approved as f64 * params.approved_proposal_bonus)
+            .min(50.0);
         vote_score + proposal_score
     }
 
@@ -105,13 +645,25 @@ impl ScoreMetric for GovernanceScoreMetric {
         "governance"
     }
 
+    fn explain(&self, data: &ChainData, config: &ScoringConfig) -> String {
+        let params = &config.governance_params;
+        format!(
+            "{} ({} votes × {} = {:.2} capped at 50; {} proposals × {} + {} approved × {} = {:.2} capped at 50)",
+            explain_score(self.get_name(), self.calculate(data, config), config.metric_max.governance),
+            data.governance_votes, params.vote_points,
+            data.governance_votes as f64 * params.vote_points,
+            data.governance_proposals, params.proposal_points,
+            data.governance_proposals_approved, params.approved_proposal_bonus,
+            data.governance_proposals as f64 * params.proposal_points
+                + data.governance_proposals_approved as f64 * params.approved_proposal_bonus,
+        )
+    }
+
     fn validate_data(&self, data: &ChainData) -> Result<(), &'static str> {
-        if data.governance_votes > 10000 {
-            return Err("Unrealistic governance votes count");
-        }
-        if data.governance_proposals > 1000 {
-            return Err("Unrealistic proposals count");
-        }
+        in_range(data.governance_votes, 0, 10000, "Unrealistic governance votes count")?;
+        in_range(data.governance_proposals, 0, 1000, "Unrealistic proposals count")?;
+        in_range(data.governance_proposals_approved, 0, data.governance_proposals,
+                 "More approved proposals than proposals")?;
         Ok(())
     }
 }
@@ -133,6 +685,16 @@ impl ScoreMetric for StakingScoreMetric {
         "staking"
     }
 
+    fn explain(&self, data: &ChainData, config: &ScoringConfig) -> String {
+        let days = data.staking_duration as f64 / 86400.0;
+        format!(
+            "{} (amount {}: ln × 10 = {:.2} capped at 60; {:.1} days: √days × 5 = {:.2} capped at 40)",
+            explain_score(self.get_name(), self.calculate(data, config), config.metric_max.staking),
+            data.staking_amount, (data.staking_amount as f64).ln() * 10.0,
+            days, days.sqrt() * 5.0,
+        )
+    }
+
     fn validate_data(&self, data: &ChainData) -> Result<(), &'static str> {
         if data.staking_amount == 0 && data.staking_duration > 0 {
             return Err("Invalid staking data: duration without amount");
@@ -146,7 +708,17 @@ pub struct IdentityScoreMetric;
 impl ScoreMetric for IdentityScoreMetric {
     fn calculate(&self, data: &ChainData, _config: &ScoringConfig) -> f64 {
         let verified_score = if data.identity_verified { 50.0 } else { 0.0 };
-        let judgement_score = (data.identity_judgements as f64 * 10.0).min(50.0);
+        
+        // Prefer per-judgement levels; fall back to the raw count when only that is known
+        let judgement_score = if data.identity_judgement_levels.is_empty() {
+            (data.identity_judgements as f64 * 10.0).min(50.0)
+        } else {
+            data.identity_judgement_levels.iter()
+                .map(JudgementLevel::weight)
+                .sum::<f64>()
+                .clamp(0.0, 50.0)
+        };
+        
         verified_score + judgement_score
     }
 
@@ -158,21 +730,50 @@ impl ScoreMetric for IdentityScoreMetric {
         "identity"
     }
 
+    fn explain(&self, data: &ChainData, config: &ScoringConfig) -> String {
+        let judgements = if data.identity_judgement_levels.is_empty() {
+            format!("{} judgements × 10", data.identity_judgements)
+        } else {
+            format!("{} weighted judgement levels", data.identity_judgement_levels.len())
+        };
+        format!(
+            "{} ({} +{}; {} capped at 50)",
+            explain_score(self.get_name(), self.calculate(data, config), config.metric_max.identity),
+            if data.identity_verified { "verified" } else { "unverified" },
+            if data.identity_verified { 50 } else { 0 },
+            judgements,
+        )
+    }
+
     fn validate_data(&self, data: &ChainData) -> Result<(), &'static str> {
-        if data.identity_judgements > 10 {
-            return Err("Unrealistic judgements count");
-        }
+        in_range(data.identity_judgements, 0, 10, "Unrealistic judgements count")?;
+        in_range(data.identity_judgement_levels.len(), 0, 10, "Unrealistic judgements count")?;
         Ok(())
     }
 }
 
+/// Community points lost per downvote received for every upvote.
+pub const DOWNVOTE_RATIO_PENALTY: f64 = 20.0;
+
+/// Maximum community points lost to downvotes.
+pub const DOWNVOTE_PENALTY_CAP: f64 = 30.0;
+
 pub struct CommunityScoreMetric;
 
+impl CommunityScoreMetric {
+    /// Penalty proportional to downvotes per upvote (an account with no
+    /// upvotes counts as having one), capped at `DOWNVOTE_PENALTY_CAP`.
+    pub fn downvote_penalty(data: &ChainData) -> f64 {
+        let ratio = data.community_downvotes as f64 / data.community_upvotes.max(1) as f64;
+        (ratio * DOWNVOTE_RATIO_PENALTY).min(DOWNVOTE_PENALTY_CAP)
+    }
+}
+
 impl ScoreMetric for CommunityScoreMetric {
     fn calculate(&self, data: &ChainData, _config: &ScoringConfig) -> f64 {
         let post_score = (data.community_posts as f64 * 1.0).min(40.0);
         let upvote_score = (data.community_upvotes as f64 * 0.5).min(60.0);
-        post_score + upvote_score
+        (post_score + upvote_score - Self::downvote_penalty(data)).max(0.0)
     }
 
     fn get_weight(&self, config: &ScoringConfig) -> f64 {
@@ -183,18 +784,47 @@ impl ScoreMetric for CommunityScoreMetric {
         "community"
     }
 
+    fn explain(&self, data: &ChainData, config: &ScoringConfig) -> String {
+        format!(
+            "{} ({} posts capped at 40; {} upvotes × 0.5 capped at 60; {} downvotes −{:.2})",
+            explain_score(self.get_name(), self.calculate(data, config), config.metric_max.community),
+            data.community_posts,
+            data.community_upvotes,
+            data.community_downvotes,
+            Self::downvote_penalty(data),
+        )
+    }
+
     fn validate_data(&self, data: &ChainData) -> Result<(), &'static str> {
-        if data.community_upvotes > data.community_posts * 100 {
-            return Err("Suspicious upvote ratio");
-        }
+        let max_votes = data.community_posts.saturating_mul(100);
+        in_range(data.community_upvotes, 0, max_votes, "Suspicious upvote ratio")?;
+        in_range(data.community_downvotes, 0, max_votes, "Suspicious downvote ratio")?;
         Ok(())
     }
 }
 
+/// Normalized per-metric scores, before weighting.
+#[derive(Debug, Clone, Copy)]
+struct MetricScores {
+    governance: f64,
+    staking: f64,
+    identity: f64,
+    community: f64,
+}
+
+/// The last record scored for an account, kept for `apply_delta`.
+/// `metric_scores` is `None` when the record was scored in fixed-point mode
+/// or under a config that has since been replaced.
+struct Baseline {
+    data: ChainData,
+    metric_scores: Option<MetricScores>,
+}
+
 pub struct ScoringEngine {
     config: ScoringConfig,
     metrics: Vec<Box<dyn ScoreMetric>>,
     score_history: HashMap<String, Vec<ScoreResult>>,
+    latest_data: HashMap<String, Baseline>,
 }
 
 impl ScoringEngine {
@@ -210,18 +840,126 @@ impl ScoringEngine {
             config,
             metrics,
             score_history: HashMap::new(),
+            latest_data: HashMap::new(),
         }
     }
 
     pub fn calculate_score(&mut self, data: ChainData) -> Result<ScoreResult, &'static str> {
+        let result = self.decayed_score(&data)?;
+        let metric_scores = (!self.config.fixed_point).then_some(MetricScores {
+            governance: result.governance_score,
+            staking: result.staking_score,
+            identity: result.identity_score,
+            community: result.community_score,
+        });
+
+        self.record_score(&result, data, metric_scores);
+        Ok(result)
+    }
+
+    fn record_score(&mut self, result: &ScoreResult, data: ChainData, metric_scores: Option<MetricScores>) {
+        self.log_score_calculation(result);
+        self.store_score_history(result.clone());
+        self.latest_data.insert(data.account_id.clone(), Baseline { data, metric_scores });
+    }
+
+    /// Human-readable account of how `data` would be scored: one line per
+    /// metric, then a total line (or the reason the record would be
+    /// rejected). Like `score_once`, ignores history and time decay.
+    pub fn explain(&self, data: &ChainData) -> Vec<String> {
+        let mut lines: Vec<String> = self.metrics.iter()
+            .map(|metric| metric.explain(data, &self.config))
+            .collect();
+
+        lines.push(match self.score_once(data) {
+            Ok(result) => format!(
+                "total: {:.2} (weights {}/{}/{}/{}, penalties −{:.2})",
+                result.total_score,
+                self.config.governance_weight,
+                self.config.staking_weight,
+                self.config.identity_weight,
+                self.config.community_weight,
+                result.breakdown.negative_adjustments,
+            ),
+            Err(reason) => format!("rejected: {}", reason),
+        });
+
+        lines
+    }
+
+    fn check_activity(&self, data: &ChainData) -> Result<(), &'static str> {
+        if data.total_actions() < self.config.min_total_actions {
+            return Err("insufficient_activity");
+        }
+        Ok(())
+    }
+
+    fn check_record(&self, data: &ChainData) -> Result<(), &'static str> {
         for metric in &self.metrics {
-            metric.validate_data(&data)?;
+            metric.validate_data(data)?;
+        }
+        self.check_activity(data)
+    }
+
+    /// Scores a single record without touching history, logging or time decay.
+    pub fn score_once(&self, data: &ChainData) -> Result<ScoreResult, &'static str> {
+        if self.config.fixed_point {
+            return self.build_fixed_score(data, 0);
+        }
+        self.build_score(data, 1.0)
+    }
+
+    /// Scores a record with time decay against the history recorded so far,
+    /// without updating it. Several records for one account scored through
+    /// here (e.g. in one parallel batch) all decay against the same earlier
+    /// score.
+    fn decayed_score(&self, data: &ChainData) -> Result<ScoreResult, &'static str> {
+        if self.config.fixed_point {
+            let decay_days = self.decay_elapsed(data).map_or(0, |secs| secs / 86400);
+            return self.build_fixed_score(data, decay_days);
+        }
+        self.build_score(data, self.time_decay_factor(data))
+    }
+
+    /// Seconds since the account's last recorded score, or `None` when time
+    /// decay is disabled or the account has no history.
+    fn decay_elapsed(&self, data: &ChainData) -> Option<u64> {
+        if self.config.time_decay_enabled {
+            self.seconds_since_last_score(&data.account_id, data.timestamp)
+        } else {
+            None
+        }
+    }
+
+    fn time_decay_factor(&self, data: &ChainData) -> f64 {
+        match self.decay_elapsed(data) {
+            Some(secs) => self.config.time_decay_rate.powf(secs as f64 / 86400.0),
+            None => 1.0,
         }
+    }
+
+    fn build_score(&self, data: &ChainData, time_decay_factor: f64) -> Result<ScoreResult, &'static str> {
+        self.check_record(data)?;
 
-        let governance_score = GovernanceScoreMetric.calculate(&data, &self.config);
-        let staking_score = StakingScoreMetric.calculate(&data, &self.config);
-        let identity_score = IdentityScoreMetric.calculate(&data, &self.config);
-        let community_score = CommunityScoreMetric.calculate(&data, &self.config);
+        let max = &self.config.metric_max;
+        let scores = MetricScores {
+            governance: normalize_metric(GovernanceScoreMetric.calculate(data, &self.config), max.governance),
+            staking: normalize_metric(StakingScoreMetric.calculate(data, &self.config), max.staking),
+            identity: normalize_metric(IdentityScoreMetric.calculate(data, &self.config), max.identity),
+            community: normalize_metric(CommunityScoreMetric.calculate(data, &self.config), max.community),
+        };
+        Ok(self.combine_scores(data, scores, time_decay_factor))
+    }
+
+    /// Weights normalized metric scores into a result, applying time decay,
+    /// penalties and the configured score bounds.
+    fn combine_scores(&self, data: &ChainData, scores: MetricScores, time_decay_factor: f64) -> ScoreResult {
+        let MetricScores {
+            governance: governance_score,
+            staking: staking_score,
+            identity: identity_score,
+            community: community_score,
+        } = scores;
 
         let weighted_governance = governance_score * self.config.governance_weight;
         let weighted_staking = staking_score * self.config.staking_weight;
@@ -233,16 +971,10 @@ impl ScoringEngine {
             + weighted_identity 
             + weighted_community;
 
-        let time_decay_factor = if self.config.time_decay_enabled {
-            self.apply_time_decay(&data.account_id, data.timestamp)
-        } else {
-            1.0
-        };
-
         total_score *= time_decay_factor;
 
         let negative_adjustments = if self.config.negative_scoring_enabled {
-            self.calculate_negative_adjustments(&data)
+            self.calculate_negative_adjustments(data)
         } else {
             0.0
         };
@@ -250,7 +982,7 @@ impl ScoringEngine {
         total_score -= negative_adjustments;
         total_score = total_score.max(self.config.min_score).min(self.config.max_score);
 
-        let result = ScoreResult {
+        ScoreResult {
             account_id: data.account_id.clone(),
             total_score,
             governance_score,
@@ -266,23 +998,179 @@ impl ScoringEngine {
                 time_decay_factor,
                 negative_adjustments,
             },
+            fixed_score: None,
+        }
+    }
+
+    /// Integer counterpart of `build_score`. Every component is computed in
+    /// units of `FIXED_POINT_SCALE` (1 point = 1,000,000 units); config
+    /// weights and bounds are converted once by rounding. Results agree with
+    /// the f64 path to within a few units, except that with a zero staking
+    /// amount the duration points still count here, while the f64 path's
+    /// ln(0) normalizes the whole staking score to 0.
+    fn build_fixed_score(&self, data: &ChainData, decay_days: u64) -> Result<ScoreResult, &'static str> {
+        self.check_record(data)?;
+
+        let scale = FIXED_POINT_SCALE;
+
+        let params = &self.config.governance_params;
+        let governance = (data.governance_votes as u64 * to_fixed(params.vote_points)).min(50 * scale)
+            + (data.governance_proposals as u64 * to_fixed(params.proposal_points)
+                + data.governance_proposals_approved as u64 * to_fixed(params.approved_proposal_bonus))
+                .min(50 * scale);
+
+        let amount = if data.staking_amount == 0 {
+            0
+        } else {
+            (fixed_ln(data.staking_amount) * 10).min(60 * scale)
         };
+        // sqrt(duration / 86400) * scale == sqrt(duration * scale^2 / 86400)
+        let days_root = isqrt(data.staking_duration as u128 * (scale as u128).pow(2) / 86400) as u64;
+        let staking = amount + (days_root * 5).min(40 * scale);
 
-        self.log_score_calculation(&result);
-        self.store_score_history(result.clone());
+        let verified = if data.identity_verified { 50 * scale } else { 0 };
+        let judgements = if data.identity_judgement_levels.is_empty() {
+            (data.identity_judgements as u64 * 10).min(50)
+        } else {
+            data.identity_judgement_levels.iter()
+                .map(|level| level.weight() as i64)
+                .sum::<i64>()
+                .clamp(0, 50) as u64
+        };
+        let identity = verified + judgements * scale;
+
+        let downvote_penalty = (data.community_downvotes as u64 * DOWNVOTE_RATIO_PENALTY as u64 * scale
+            / data.community_upvotes.max(1) as u64)
+            .min(DOWNVOTE_PENALTY_CAP as u64 * scale);
+        let community = ((data.community_posts as u64).min(40) * scale
+            + (data.community_upvotes as u64 * scale / 2).min(60 * scale))
+            .saturating_sub(downvote_penalty);
+
+        let max = &self.config.metric_max;
+        let governance = normalize_metric_fixed(governance, max.governance);
+        let staking = normalize_metric_fixed(staking, max.staking);
+        let identity = normalize_metric_fixed(identity, max.identity);
+        let community = normalize_metric_fixed(community, max.community);
+
+        let weighted_governance = governance * to_fixed(self.config.governance_weight) / scale;
+        let weighted_staking = staking * to_fixed(self.config.staking_weight) / scale;
+        let weighted_identity = identity * to_fixed(self.config.identity_weight) / scale;
+        let weighted_community = community * to_fixed(self.config.community_weight) / scale;
+
+        let decay = fixed_pow(to_fixed(self.config.time_decay_rate), decay_days);
+        let mut total = ((weighted_governance + weighted_staking + weighted_identity + weighted_community)
+            as u128 * decay as u128 / scale as u128) as i64;
+
+        let negative_adjustments = if self.config.negative_scoring_enabled {
+            let mut penalty = 0;
+            if !data.identity_verified {
+                penalty += 5;
+            }
+            if data.governance_votes == 0 && data.governance_proposals == 0 {
+                penalty += 3;
+            }
+            if data.staking_amount == 0 {
+                penalty += 2;
+            }
+            penalty * scale as i64
+        } else {
+            0
+        };
+
+        total -= negative_adjustments;
+        let min = to_fixed(self.config.min_score) as i64;
+        let max = (to_fixed(self.config.max_score) as i64).max(min);
+        let total = total.clamp(min, max).clamp(0, u32::MAX as i64) as u32;
+
+        let points = |units: u64| units as f64 / scale as f64;
+
+        Ok(ScoreResult {
+            account_id: data.account_id.clone(),
+            total_score: points(total as u64),
+            governance_score: points(governance),
+            staking_score: points(staking),
+            identity_score: points(identity),
+            community_score: points(community),
+            timestamp: data.timestamp,
+            breakdown: ScoreBreakdown {
+                weighted_governance: points(weighted_governance),
+                weighted_staking: points(weighted_staking),
+                weighted_identity: points(weighted_identity),
+                weighted_community: points(weighted_community),
+                time_decay_factor: points(decay),
+                negative_adjustments: points(negative_adjustments as u64),
+            },
+            fixed_score: Some(total),
+        })
+    }
+
+    /// Updates an account's last result by applying `delta` to the last
+    /// `ChainData` seen for it.
+    ///
+    /// Only the metrics whose inputs `delta` touches are recalculated; the rest
+    /// are reused from the last result. Validation, the activity check,
+    /// penalties and time decay still run on the updated record, so the result
+    /// equals what `calculate_score` would return for it. In fixed-point mode,
+    /// or after `update_config`, the next call falls back to a full rescore.
+    ///
+    /// The first call for an account needs a full baseline from `calculate_score`;
+    /// without one this returns an error rather than scoring from zero. The
+    /// engine keeps one baseline record per account until `remove_baseline`.
+    pub fn apply_delta(&mut self, account_id: &str, delta: ChainDataDelta) -> Result<ScoreResult, &'static str> {
+        let baseline = self.latest_data
+            .get(account_id)
+            .ok_or("No baseline data for account")?;
+
+        let mut data = baseline.data.clone();
+        delta.apply_to(&mut data);
+
+        let cached = match baseline.metric_scores {
+            Some(scores) if !self.config.fixed_point => scores,
+            _ => return self.calculate_score(data),
+        };
+
+        self.check_record(&data)?;
+
+        let max = &self.config.metric_max;
+        let scores = MetricScores {
+            governance: if delta.touches_governance() {
+                normalize_metric(GovernanceScoreMetric.calculate(&data, &self.config), max.governance)
+            } else {
+                cached.governance
+            },
+            staking: if delta.touches_staking() {
+                normalize_metric(StakingScoreMetric.calculate(&data, &self.config), max.staking)
+            } else {
+                cached.staking
+            },
+            identity: if delta.touches_identity() {
+                normalize_metric(IdentityScoreMetric.calculate(&data, &self.config), max.identity)
+            } else {
+                cached.identity
+            },
+            community: if delta.touches_community() {
+                normalize_metric(CommunityScoreMetric.calculate(&data, &self.config), max.community)
+            } else {
+                cached.community
+            },
+        };
 
+        let result = self.combine_scores(&data, scores, self.time_decay_factor(&data));
+        self.record_score(&result, data, Some(scores));
         Ok(result)
     }
 
-    fn apply_time_decay(&self, account_id: &str, current_timestamp: u64) -> f64 {
-        if let Some(history) = self.score_history.get(account_id) {
-            if let Some(last_score) = history.last() {
-                let time_diff = current_timestamp.saturating_sub(last_score.timestamp);
-                let days_elapsed = time_diff as f64 / 86400.0;
-                return self.config.time_decay_rate.powf(days_elapsed);
-            }
-        }
-        1.0
+    /// Drops the record kept for `apply_delta` on `account_id`, returning it.
+    /// History is left alone.
+    pub fn remove_baseline(&mut self, account_id: &str) -> Option<ChainData> {
+        self.latest_data.remove(account_id).map(|baseline| baseline.data)
+    }
+
+    fn seconds_since_last_score(&self, account_id: &str, current_timestamp: u64) -> Option<u64> {
+        self.score_history
+            .get(account_id)
+            .and_then(|history| history.last())
+            .map(|last_score| current_timestamp.saturating_sub(last_score.timestamp))
     }
 
     fn calculate_negative_adjustments(&self, data: &ChainData) -> f64 {
@@ -342,8 +1230,35 @@ impl ScoringEngine {
             .collect()
     }
 
+    /// Lazily scores records as the returned iterator is consumed, so the
+    /// input is never buffered. Each item goes through `calculate_score`:
+    /// history, latest data, time decay state and logging are updated per
+    /// item as it is pulled, so engine memory still grows with every record
+    /// scored. Records never pulled are never scored. Use
+    /// `score_stream_readonly` when memory has to stay bounded.
+    pub fn score_stream<'a, I>(&'a mut self, iter: I) -> impl Iterator<Item = Result<ScoreResult, &'static str>> + 'a
+    where
+        I: Iterator<Item = ChainData> + 'a,
+    {
+        iter.map(move |data| self.calculate_score(data))
+    }
+
+    /// Lazily scores records without recording them: time decay reads the
+    /// history already in the engine, but nothing is stored or logged, so
+    /// memory stays bounded regardless of input size.
+    pub fn score_stream_readonly<'a, I>(&'a self, iter: I) -> impl Iterator<Item = Result<ScoreResult, &'static str>> + 'a
+    where
+        I: Iterator<Item = ChainData> + 'a,
+    {
+        iter.map(move |data| self.decayed_score(&data))
+    }
+
     pub fn update_config(&mut self, new_config: ScoringConfig) {
         self.config = new_config;
+        // Cached metric scores were computed under the old config
+        for baseline in self.latest_data.values_mut() {
+            baseline.metric_scores = None;
+        }
     }
 
     pub fn export_history_json(&self, account_id: &str) -> Result<String, &'static str> {
@@ -370,10 +1285,120 @@ impl ScoringEngine {
     }
 }
 
-pub struct DataCleaner;
+/// Reputation tier for a total score. Thresholds (90/80/50/30) match the
+/// defaults of `dotrepute_core::scoring::TierConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReputationTier {
+    Elite,
+    High,
+    Medium,
+    Low,
+    Unverified,
+}
 
-impl DataCleaner {
-    pub fn normalize_chain_data(data: &mut ChainData) {
+impl ReputationTier {
+    pub fn from_score(total_score: f64) -> Self {
+        if total_score >= 90.0 {
+            ReputationTier::Elite
+        } else if total_score >= 80.0 {
+            ReputationTier::High
+        } else if total_score >= 50.0 {
+            ReputationTier::Medium
+        } else if total_score >= 30.0 {
+            ReputationTier::Low
+        } else {
+            ReputationTier::Unverified
+        }
+    }
+}
+
+/// Extra per-domain score attached to a report, e.g. from the index
+/// managers' `ReputationAggregator::domain_scores`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainScore {
+    pub domain: String,
+    pub score: f64,
+}
+
+/// Complete per-account report for frontends and other integrators.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReputationReport {
+    pub account_id: String,
+    pub tier: ReputationTier,
+    pub total_score: f64,
+    pub governance_score: f64,
+    pub staking_score: f64,
+    pub identity_score: f64,
+    pub community_score: f64,
+    #[serde(default)]
+    pub domain_scores: Vec<DomainScore>,
+    #[serde(default)]
+    pub anomalies: Vec<String>,
+    pub last_updated: u64,
+}
+
+impl ReputationReport {
+    pub fn builder(result: &ScoreResult) -> ReputationReportBuilder {
+        ReputationReportBuilder {
+            report: ReputationReport {
+                account_id: result.account_id.clone(),
+                tier: ReputationTier::from_score(result.total_score),
+                total_score: result.total_score,
+                governance_score: result.governance_score,
+                staking_score: result.staking_score,
+                identity_score: result.identity_score,
+                community_score: result.community_score,
+                domain_scores: Vec::new(),
+                anomalies: Vec::new(),
+                last_updated: result.timestamp,
+            },
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, &'static str> {
+        #[cfg(feature = "std")]
+        {
+            serde_json::to_string_pretty(self)
+                .map_err(|_| "JSON serialization failed")
+        }
+
+        #[cfg(not(feature = "std"))]
+        Err("JSON export not available in no_std environment")
+    }
+}
+
+/// Assembles a `ReputationReport` from a `ScoreResult` plus optional extras.
+pub struct ReputationReportBuilder {
+    report: ReputationReport,
+}
+
+impl ReputationReportBuilder {
+    /// Records `DataCleaner::detect_anomalies` for the data that was scored.
+    pub fn with_anomalies(mut self, data: &ChainData) -> Self {
+        self.report.anomalies = DataCleaner::detect_anomalies(data)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        self
+    }
+
+    pub fn with_domain_score(mut self, domain: &str, score: f64) -> Self {
+        self.report.domain_scores.push(DomainScore {
+            domain: String::from(domain),
+            score,
+        });
+        self
+    }
+
+    pub fn build(self) -> ReputationReport {
+        self.report
+    }
+}
+
+pub struct DataCleaner;
+
+impl DataCleaner {
+    pub fn normalize_chain_data(data: &mut ChainData) {
         if data.governance_votes > 10000 {
             data.governance_votes = 10000;
         }
@@ -427,6 +1452,81 @@ impl DataCleaner {
     }
 }
 
+/// ChainData fields tracked by `WindowAnomalyDetector`.
+const WINDOW_FIELDS: [&str; 5] = [
+    "governance_votes",
+    "governance_proposals",
+    "staking_amount",
+    "community_posts",
+    "community_upvotes",
+];
+
+/// Flags ChainData values that stray too far from recent history, catching
+/// relative anomalies the fixed `DataCleaner::detect_anomalies` thresholds miss.
+///
+/// Keeps the last `window_size` records and flags a field when it lies more
+/// than `k` standard deviations from that field's window mean. Nothing is
+/// flagged until the window is full. A field with zero variance in the window
+/// is flagged on any change.
+pub struct WindowAnomalyDetector {
+    window_size: usize,
+    k: f64,
+    window: VecDeque<[f64; WINDOW_FIELDS.len()]>,
+}
+
+impl WindowAnomalyDetector {
+    /// `window_size` is clamped to at least 2; `k` is typically 2.0-3.0.
+    pub fn new(window_size: usize, k: f64) -> Self {
+        let window_size = window_size.max(2);
+        Self {
+            window_size,
+            k,
+            window: VecDeque::with_capacity(window_size),
+        }
+    }
+
+    /// Checks `data` against the current window, then adds it to the window.
+    pub fn push(&mut self, data: &ChainData) -> Vec<String> {
+        let values = [
+            data.governance_votes as f64,
+            data.governance_proposals as f64,
+            data.staking_amount as f64,
+            data.community_posts as f64,
+            data.community_upvotes as f64,
+        ];
+
+        let mut anomalies = Vec::new();
+        if self.window.len() >= self.window_size {
+            let n = self.window.len() as f64;
+            for (field, name) in WINDOW_FIELDS.iter().enumerate() {
+                let mean = self.window.iter().map(|v| v[field]).sum::<f64>() / n;
+                let variance = self.window.iter()
+                    .map(|v| (v[field] - mean) * (v[field] - mean))
+                    .sum::<f64>() / n;
+                let std_dev = variance.sqrt();
+                let deviation = (values[field] - mean).abs();
+
+                let anomalous = if std_dev == 0.0 {
+                    deviation > 0.0
+                } else {
+                    deviation > self.k * std_dev
+                };
+                if anomalous {
+                    anomalies.push(format!(
+                        "{} {} deviates from window mean {:.1} (std {:.1})",
+                        name, values[field], mean, std_dev
+                    ));
+                }
+            }
+
+            self.window.pop_front();
+        }
+
+        self.window.push_back(values);
+        anomalies
+    }
+}
+
 #[cfg(feature = "std")]
 pub mod parsers {
     use super::*;
@@ -448,12 +1548,19 @@ pub mod parsers {
             account_id: fields[0].to_string(),
             governance_votes: fields[1].parse().map_err(|_| "Invalid votes")?,
             governance_proposals: fields[2].parse().map_err(|_| "Invalid proposals")?,
+            governance_proposals_approved: 0,
             staking_amount: fields[3].parse().map_err(|_| "Invalid staking amount")?,
             staking_duration: fields[4].parse().map_err(|_| "Invalid staking duration")?,
             identity_verified: fields[5].parse().map_err(|_| "Invalid identity flag")?,
             identity_judgements: fields[6].parse().map_err(|_| "Invalid judgements")?,
+            identity_judgement_levels: Vec::new(),
             community_posts: fields[7].parse().map_err(|_| "Invalid posts")?,
             community_upvotes: fields[8].parse().map_err(|_| "Invalid upvotes")?,
+            // Optional trailing column, absent in older exports
+            community_downvotes: match fields.get(10) {
+                Some(field) => field.parse().map_err(|_| "Invalid downvotes")?,
+                None => 0,
+            },
             timestamp: fields[9].parse().map_err(|_| "Invalid timestamp")?,
         })
     }
@@ -466,50 +1573,34 @@ use rayon::prelude::*;
 impl ScoringEngine {
     pub fn calculate_parallel_scores(&self, data_batch: Vec<ChainData>) -> Vec<Result<ScoreResult, &'static str>> {
         data_batch.par_iter()
-            .map(|data| {
-                for metric in &self.metrics {
-                    if let Err(e) = metric.validate_data(data) {
-                        return Err(e);
-                    }
-                }
-
-                let governance_score = GovernanceScoreMetric.calculate(data, &self.config);
-                let staking_score = StakingScoreMetric.calculate(data, &self.config);
-                let identity_score = IdentityScoreMetric.calculate(data, &self.config);
-                let community_score = CommunityScoreMetric.calculate(data, &self.config);
-
-                let weighted_governance = governance_score * self.config.governance_weight;
-                let weighted_staking = staking_score * self.config.staking_weight;
-                let weighted_identity = identity_score * self.config.identity_weight;
-                let weighted_community = community_score * self.config.community_weight;
-
-                let mut total_score = weighted_governance 
-                    + weighted_staking 
-                    + weighted_identity 
-                    + weighted_community;
-
-                total_score = total_score.max(self.config.min_score).min(self.config.max_score);
-
-                Ok(ScoreResult {
-                    account_id: data.account_id.clone(),
-                    total_score,
-                    governance_score,
-                    staking_score,
-                    identity_score,
-                    community_score,
-                    timestamp: data.timestamp,
-                    breakdown: ScoreBreakdown {
-                        weighted_governance,
-                        weighted_staking,
-                        weighted_identity,
-                        weighted_community,
-                        time_decay_factor: 1.0,
-                        negative_adjustments: 0.0,
-                    },
-                })
-            })
+            .map(|data| self.decayed_score(data))
             .collect()
     }
+
+    /// Parallel scoring over `chunk_size` records per task, which amortizes
+    /// rayon's scheduling overhead when per-record work is cheap. Runs on
+    /// `pool` if given (e.g. a `ThreadPoolBuilder` pool with capped threads),
+    /// otherwise on rayon's global pool. A `chunk_size` of 0 is treated as 1.
+    /// Results keep input order.
+    pub fn calculate_parallel_scores_chunked(
+        &self,
+        data_batch: &[ChainData],
+        chunk_size: usize,
+        pool: Option<&rayon::ThreadPool>,
+    ) -> Vec<Result<ScoreResult, &'static str>> {
+        let chunk_size = chunk_size.max(1);
+        let run = || {
+            data_batch.par_chunks(chunk_size)
+                .flat_map_iter(|chunk| chunk.iter().map(|data| self.decayed_score(data)))
+                .collect()
+        };
+
+        match pool {
+            Some(pool) => pool.install(run),
+            None => run(),
+        }
+    }
+
 }
 
 #[cfg(test)]
@@ -521,16 +1612,91 @@ mod tests {
             account_id: String::from("test_account"),
             governance_votes: 50,
             governance_proposals: 5,
+            governance_proposals_approved: 0,
             staking_amount: 1000000000000,
             staking_duration: 2592000,
             identity_verified: true,
             identity_judgements: 2,
+            identity_judgement_levels: Vec::new(),
             community_posts: 100,
             community_upvotes: 500,
+            community_downvotes: 0,
             timestamp: 1699430400,
         }
     }
 
+    fn account(account_id: &str) -> ChainData {
+        ChainData { account_id: String::from(account_id), ..create_test_data() }
+    }
+
+    #[test]
+    fn test_chaindata_diff() {
+        let old = vec![account("alice"), account("bob"), account("carol")];
+
+        let mut bob = account("bob");
+        bob.governance_votes += 3;
+        bob.staking_amount -= 1000;
+        bob.identity_verified = false;
+        let new = vec![account("dave"), bob, account("alice")];
+
+        let changes = chaindata_diff(&old, &new);
+        assert_eq!(changes.len(), 3);
+
+        match &changes[0] {
+            ChainDataChange::Added(data) => assert_eq!(data.account_id, "dave"),
+            other => panic!("expected dave added, got {:?}", other),
+        }
+        match &changes[1] {
+            ChainDataChange::Modified { account_id, deltas, judgement_levels_changed } => {
+                assert_eq!(account_id, "bob");
+                assert!(!judgement_levels_changed);
+                let fields: Vec<(&str, i128)> = deltas.iter().map(|d| (d.field, d.delta())).collect();
+                assert_eq!(fields, vec![("governance_votes", 3), ("staking_amount", -1000), ("identity_verified", -1)]);
+            }
+            other => panic!("expected bob modified, got {:?}", other),
+        }
+        match &changes[2] {
+            ChainDataChange::Removed(account_id) => assert_eq!(account_id, "carol"),
+            other => panic!("expected carol removed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_chaindata_diff_edge_cases() {
+        assert!(chaindata_diff(&[], &[]).is_empty());
+        assert!(chaindata_diff(&[account("alice")], &[account("alice")]).is_empty());
+
+        let changes = chaindata_diff(&[], &[account("alice"), account("alice")]);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(changes[0], ChainDataChange::Added(_)));
+
+        let changes = chaindata_diff(&[account("alice"), account("alice")], &[]);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], ChainDataChange::Removed(id) if id == "alice"));
+
+        // Judgement levels are compared by content, not just by count
+        let mut old = account("alice");
+        old.identity_judgement_levels = vec![JudgementLevel::Reasonable];
+        let mut new = account("alice");
+        new.identity_judgement_levels = vec![JudgementLevel::KnownGood];
+        match &chaindata_diff(&[old.clone()], &[new.clone()])[0] {
+            ChainDataChange::Modified { deltas, judgement_levels_changed, .. } => {
+                assert!(deltas.is_empty());
+                assert!(judgement_levels_changed);
+            }
+            other => panic!("expected modified, got {:?}", other),
+        }
+
+        new.identity_judgement_levels.push(JudgementLevel::Reasonable);
+        match &chaindata_diff(&[old], &[new])[0] {
+            ChainDataChange::Modified { deltas, judgement_levels_changed, .. } => {
+                assert_eq!(deltas, &vec![FieldDelta { field: "identity_judgement_levels", old: 1, new: 2 }]);
+                assert!(judgement_levels_changed);
+            }
+            other => panic!("expected modified, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_governance_metric() {
         let metric = GovernanceScoreMetric;
@@ -563,6 +1729,43 @@ mod tests {
         assert!(score >= 50.0);
     }
 
+    #[test]
+    fn test_identity_judgement_levels() {
+        let metric = IdentityScoreMetric;
+        let config = ScoringConfig::default();
+        let mut data = create_test_data();
+        data.identity_judgement_levels = vec![JudgementLevel::KnownGood, JudgementLevel::Reasonable];
+        
+        // 50 verified + 20 + 10
+        assert_eq!(metric.calculate(&data, &config), 80.0);
+    }
+
+    #[test]
+    fn test_erroneous_judgement_does_not_raise_score() {
+        let metric = IdentityScoreMetric;
+        let config = ScoringConfig::default();
+        let mut data = create_test_data();
+        data.identity_judgement_levels = vec![JudgementLevel::Reasonable];
+        let baseline = metric.calculate(&data, &config);
+        
+        data.identity_judgement_levels.push(JudgementLevel::Erroneous);
+        assert!(metric.calculate(&data, &config) <= baseline);
+        
+        data.identity_judgement_levels = vec![JudgementLevel::Erroneous];
+        assert_eq!(metric.calculate(&data, &config), 50.0);
+    }
+
+    #[test]
+    fn test_judgement_count_fallback() {
+        let metric = IdentityScoreMetric;
+        let config = ScoringConfig::default();
+        let data = create_test_data();
+        
+        // No levels recorded: 2 judgements at 10 points each
+        assert!(data.identity_judgement_levels.is_empty());
+        assert_eq!(metric.calculate(&data, &config), 70.0);
+    }
+
     #[test]
     fn test_community_metric() {
         let metric = CommunityScoreMetric;
@@ -597,6 +1800,209 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_explain() {
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let mut data = create_test_data();
+        data.governance_votes = 50;
+
+        let lines = engine.explain(&data);
+        assert_eq!(lines.len(), 5);
+        for (line, name) in lines.iter().zip(["governance", "staking", "identity", "community", "total"]) {
+            assert!(line.starts_with(name), "{}", line);
+        }
+        assert!(lines[0].contains("50 votes × 2 = 100.00 capped at 50"), "{}", lines[0]);
+
+        let score = engine.score_once(&data).unwrap();
+        assert!(lines[4].contains(&format!("{:.2}", score.total_score)));
+
+        data.governance_votes = 20000;
+        let lines = engine.explain(&data);
+        assert_eq!(lines[4], "rejected: Unrealistic governance votes count");
+    }
+
+    #[test]
+    fn test_explain_uses_metric_max() {
+        let mut data = create_test_data();
+        // Governance: 25 votes × 2 = 50 raw points
+        data.governance_votes = 25;
+        data.governance_proposals = 0;
+
+        let config = ScoringConfig {
+            metric_max: MetricMaxima { governance: 50.0, ..MetricMaxima::default() },
+            ..ScoringConfig::default()
+        };
+        let engine = ScoringEngine::new(config);
+        let score = engine.score_once(&data).unwrap();
+        assert_eq!(score.governance_score, 100.0);
+
+        let lines = engine.explain(&data);
+        assert!(lines[0].starts_with("governance: 100.00/100 [raw 50.00 of max 50]"), "{}", lines[0]);
+        // Metrics at the default max read as before
+        assert!(lines[1].starts_with(&format!("staking: {:.2}/100 (", score.staking_score)), "{}", lines[1]);
+
+        // The trait's default explanation normalizes the same way
+        struct Flat;
+        impl ScoreMetric for Flat {
+            fn calculate(&self, _data: &ChainData, _config: &ScoringConfig) -> f64 { 30.0 }
+            fn get_weight(&self, _config: &ScoringConfig) -> f64 { 0.0 }
+            fn get_name(&self) -> &'static str { "identity" }
+            fn validate_data(&self, _data: &ChainData) -> Result<(), &'static str> { Ok(()) }
+        }
+        let config = ScoringConfig {
+            metric_max: MetricMaxima { identity: 60.0, ..MetricMaxima::default() },
+            ..ScoringConfig::default()
+        };
+        assert_eq!(Flat.explain(&data, &config), "identity: 50.00/100 [raw 30.00 of max 60]");
+    }
+
+    #[test]
+    fn test_metric_max_normalization() {
+        let mut data = create_test_data();
+        // Governance: 25 votes × 2 = 50 raw points
+        data.governance_votes = 25;
+        data.governance_proposals = 0;
+        // Staking: both components at their caps, 100 raw points
+        data.staking_amount = u64::MAX;
+        data.staking_duration = 100 * 86400;
+
+        let equal = ScoringConfig {
+            governance_weight: 0.25,
+            staking_weight: 0.25,
+            identity_weight: 0.25,
+            community_weight: 0.25,
+            time_decay_enabled: false,
+            negative_scoring_enabled: false,
+            ..ScoringConfig::default()
+        };
+
+        // Against the default maxima, half a governance score contributes half
+        let result = ScoringEngine::new(equal.clone()).score_once(&data).unwrap();
+        assert_eq!(result.governance_score, 50.0);
+        assert!((result.breakdown.weighted_governance - 12.5).abs() < 1e-9);
+        assert!((result.breakdown.weighted_staking - 25.0).abs() < 1e-9);
+
+        // With governance measured against a max of 50, both metrics are at
+        // their max and contribute equally
+        let capped = ScoringConfig {
+            metric_max: MetricMaxima { governance: 50.0, ..MetricMaxima::default() },
+            ..equal
+        };
+        let result = ScoringEngine::new(capped.clone()).score_once(&data).unwrap();
+        assert_eq!(result.governance_score, 100.0);
+        assert!((result.breakdown.weighted_governance - result.breakdown.weighted_staking).abs() < 1e-9);
+
+        let fixed = ScoringConfig { fixed_point: true, ..capped };
+        let result = ScoringEngine::new(fixed).score_once(&data).unwrap();
+        assert_eq!(result.governance_score, 100.0);
+        assert_eq!(result.breakdown.weighted_governance, 25.0);
+        assert_eq!(result.breakdown.weighted_staking, 25.0);
+
+        assert_eq!(normalize_metric(f64::NEG_INFINITY, 100.0), 0.0);
+        assert_eq!(normalize_metric(10.0, 0.0), 0.0);
+        assert_eq!(normalize_metric_fixed(10 * FIXED_POINT_SCALE, 0.0), 0);
+    }
+
+    #[test]
+    fn test_config_validate() {
+        assert!(ScoringConfig::default().validate().is_ok());
+        for name in ScoringConfig::preset_names() {
+            assert!(ScoringConfig::preset(name).unwrap().validate().is_ok());
+        }
+
+        let config = ScoringConfig { staking_weight: -0.1, ..ScoringConfig::default() };
+        assert_eq!(config.validate(), Err("Weights must be finite and non-negative"));
+        let config = ScoringConfig {
+            governance_weight: 0.0, staking_weight: 0.0, identity_weight: 0.0, community_weight: 0.0,
+            ..ScoringConfig::default()
+        };
+        assert_eq!(config.validate(), Err("At least one weight must be positive"));
+        let config = ScoringConfig { time_decay_rate: 1.5, ..ScoringConfig::default() };
+        assert_eq!(config.validate(), Err("Time decay rate must be in (0, 1]"));
+        let config = ScoringConfig { min_score: 50.0, max_score: 10.0, ..ScoringConfig::default() };
+        assert!(config.validate().is_err());
+        let config = ScoringConfig {
+            metric_max: MetricMaxima { staking: 0.0, ..MetricMaxima::default() },
+            ..ScoringConfig::default()
+        };
+        assert_eq!(config.validate(), Err("Metric maxima must be finite and positive"));
+    }
+
+    #[test]
+    fn test_config_file_round_trip() {
+        let config = ScoringConfig {
+            metric_max: MetricMaxima { governance: 50.0, ..MetricMaxima::default() },
+            governance_params: GovernanceScoringParams { vote_points: 3.0, ..GovernanceScoringParams::default() },
+            ..ScoringConfig::preset("staking-focused").unwrap()
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(ScoringConfig::from_json(&json).unwrap(), config);
+
+        let toml_text = toml::to_string(&config).unwrap();
+        assert_eq!(ScoringConfig::from_toml(&toml_text).unwrap(), config);
+
+        // Omitted fields fall back to their defaults
+        let partial = ScoringConfig::from_toml("governance_weight = 0.7\n[metric_max]\nidentity = 20.0\n").unwrap();
+        assert_eq!(partial.governance_weight, 0.7);
+        assert_eq!(partial.staking_weight, ScoringConfig::default().staking_weight);
+        assert_eq!(partial.metric_max.identity, 20.0);
+        assert_eq!(partial.metric_max.governance, 100.0);
+
+        assert_eq!(ScoringConfig::from_json(r#"{"time_decay_rate": 0.0}"#), Err("Time decay rate must be in (0, 1]"));
+        assert_eq!(ScoringConfig::from_toml("governance_weight = "), Err("TOML parsing failed"));
+        assert_eq!(ScoringConfig::from_json("{"), Err("JSON parsing failed"));
+    }
+
+    #[test]
+    fn test_config_presets() {
+        assert_eq!(ScoringConfig::preset_names().len(), 3);
+        for name in ScoringConfig::preset_names() {
+            let config = ScoringConfig::preset(name).unwrap();
+            let weights = [config.governance_weight, config.staking_weight,
+                           config.identity_weight, config.community_weight];
+            assert!(weights.iter().all(|w| (0.0..=1.0).contains(w)), "{}", name);
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9, "{}", name);
+        }
+
+        let governance = ScoringConfig::preset("governance-focused").unwrap();
+        assert!(governance.governance_weight > governance.staking_weight);
+        let staking = ScoringConfig::preset("staking-focused").unwrap();
+        assert!(staking.staking_weight > staking.governance_weight);
+        assert_eq!(staking.max_score, ScoringConfig::default().max_score);
+
+        assert!(ScoringConfig::preset("unknown").is_none());
+    }
+
+    #[test]
+    fn test_validate_data_bounds() {
+        assert_eq!(in_range(10u32, 0, 10, "bad"), Ok(10));
+        assert_eq!(in_range(11u32, 0, 10, "bad"), Err("bad"));
+        assert_eq!(in_range(f64::NAN, 0.0, 1.0, "bad"), Err("bad"));
+        assert_eq!(in_range(5u32, 10, 0, "bad"), Err("Invalid range bounds"));
+
+        let governance = GovernanceScoreMetric;
+        let mut data = create_test_data();
+        data.governance_votes = 10000;
+        assert!(governance.validate_data(&data).is_ok());
+        data.governance_votes = 10001;
+        assert_eq!(governance.validate_data(&data), Err("Unrealistic governance votes count"));
+
+        let identity = IdentityScoreMetric;
+        let mut data = create_test_data();
+        data.identity_judgements = 10;
+        assert!(identity.validate_data(&data).is_ok());
+        data.identity_judgements = 11;
+        assert_eq!(identity.validate_data(&data), Err("Unrealistic judgements count"));
+
+        // Posts large enough to overflow posts * 100 in u32
+        let community = CommunityScoreMetric;
+        let mut data = create_test_data();
+        data.community_posts = u32::MAX;
+        data.community_upvotes = u32::MAX;
+        assert!(community.validate_data(&data).is_ok());
+    }
+
     #[test]
     fn test_data_normalization() {
         let mut data = create_test_data();
@@ -667,6 +2073,470 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_score_once() {
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let data = create_test_data();
+        
+        let first = engine.score_once(&data).unwrap();
+        let second = engine.score_once(&data).unwrap();
+        
+        assert_eq!(first.total_score, second.total_score);
+        assert_eq!(first.breakdown.time_decay_factor, 1.0);
+        assert!(engine.get_score_history("test_account").is_none());
+    }
+
+    #[test]
+    fn test_apply_delta_requires_baseline() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        
+        let result = engine.apply_delta("test_account", ChainDataDelta::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_delta() {
+        let config = ScoringConfig { time_decay_enabled: false, ..ScoringConfig::default() };
+        let mut engine = ScoringEngine::new(config.clone());
+        let mut data = create_test_data();
+        data.governance_votes = 10;
+        let _ = engine.calculate_score(data.clone());
+        
+        let delta = ChainDataDelta {
+            governance_votes: 1,
+            staking_amount: -1_000,
+            timestamp: data.timestamp + 60,
+            ..ChainDataDelta::default()
+        };
+        let incremental = engine.apply_delta("test_account", delta).unwrap();
+        
+        data.governance_votes = 11;
+        data.staking_amount -= 1_000;
+        data.timestamp += 60;
+        let full = ScoringEngine::new(config).calculate_score(data).unwrap();
+        
+        assert_eq!(incremental.governance_score, full.governance_score);
+        assert_eq!(incremental.total_score, full.total_score);
+        assert_eq!(engine.get_score_history("test_account").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_apply_delta_matches_full_rescore() {
+        let mut incremental = ScoringEngine::new(ScoringConfig::default());
+        let mut full = ScoringEngine::new(ScoringConfig::default());
+        let mut data = create_test_data();
+        incremental.calculate_score(data.clone()).unwrap();
+        full.calculate_score(data.clone()).unwrap();
+
+        let delta = ChainDataDelta {
+            community_posts: 4,
+            community_downvotes: 2,
+            timestamp: data.timestamp + 3 * 86400,
+            ..ChainDataDelta::default()
+        };
+        let updated = incremental.apply_delta("test_account", delta.clone()).unwrap();
+        delta.apply_to(&mut data);
+        let expected = full.calculate_score(data).unwrap();
+
+        assert_eq!(updated.community_score, expected.community_score);
+        assert_eq!(updated.breakdown.time_decay_factor, expected.breakdown.time_decay_factor);
+        assert_eq!(updated.total_score, expected.total_score);
+    }
+
+    #[test]
+    fn test_apply_delta_after_config_change() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        let data = create_test_data();
+        engine.calculate_score(data.clone()).unwrap();
+
+        let config = ScoringConfig { governance_weight: 0.4, staking_weight: 0.2, ..ScoringConfig::default() };
+        engine.update_config(config.clone());
+        let updated = engine.apply_delta("test_account", ChainDataDelta::default()).unwrap();
+
+        let expected = ScoringEngine::new(config).score_once(&data).unwrap();
+        assert_eq!(updated.total_score, expected.total_score);
+    }
+
+    #[test]
+    fn test_remove_baseline() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        engine.calculate_score(create_test_data()).unwrap();
+
+        assert!(engine.remove_baseline("test_account").is_some());
+        assert!(engine.apply_delta("test_account", ChainDataDelta::default()).is_err());
+        assert_eq!(engine.get_score_history("test_account").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_score_stream() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        let records = (0..3).map(|i| {
+            let mut data = create_test_data();
+            data.account_id = format!("account_{}", i);
+            data
+        });
+
+        let mut stream = engine.score_stream(records);
+        assert_eq!(stream.next().unwrap().unwrap().account_id, "account_0");
+        drop(stream);
+
+        // Only the consumed record reached history
+        assert!(engine.get_score_history("account_0").is_some());
+        assert!(engine.get_score_history("account_1").is_none());
+
+        let records = vec![create_test_data(), create_test_data()];
+        let results: Vec<_> = engine.score_stream(records.into_iter()).collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(engine.get_score_history("test_account").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_score_stream_readonly() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        let mut earlier = create_test_data();
+        earlier.timestamp -= 10 * 86400;
+        engine.calculate_score(earlier).unwrap();
+
+        let records = (0..100).map(|i| ChainData { account_id: format!("account_{}", i), ..create_test_data() });
+        let results: Vec<_> = engine.score_stream_readonly(records).collect();
+        assert_eq!(results.len(), 100);
+        assert!(results.iter().all(|r| r.is_ok()));
+        assert!(engine.get_score_history("account_0").is_none());
+
+        // Existing history still drives time decay, and is left untouched
+        let decayed = engine.score_stream_readonly(std::iter::once(create_test_data())).next().unwrap().unwrap();
+        assert!(decayed.total_score < engine.score_once(&create_test_data()).unwrap().total_score);
+        assert_eq!(engine.get_score_history("test_account").unwrap().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_chunked_parallel_scores() {
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let mut invalid = create_test_data();
+        invalid.governance_votes = 20000;
+        let batch = vec![create_test_data(), invalid, create_test_data()];
+
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        for chunk_size in [0, 1, 2, 10] {
+            let results = engine.calculate_parallel_scores_chunked(&batch, chunk_size, Some(&pool));
+            assert_eq!(results.len(), 3);
+            assert!(results[0].is_ok());
+            assert_eq!(results[1].as_ref().unwrap_err(), &"Unrealistic governance votes count");
+            assert!(results[2].is_ok());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_parallel_matches_sequential() {
+        let config = ScoringConfig { time_decay_enabled: false, ..ScoringConfig::default() };
+        let mut unverified = create_test_data();
+        unverified.account_id = String::from("unverified");
+        unverified.identity_verified = false;
+        let batch = vec![create_test_data(), unverified];
+
+        let parallel = ScoringEngine::new(config.clone()).calculate_parallel_scores(batch.clone());
+        let mut engine = ScoringEngine::new(config);
+        for (data, parallel) in batch.into_iter().zip(parallel) {
+            let sequential = engine.calculate_score(data).unwrap();
+            let parallel = parallel.unwrap();
+            assert_eq!(sequential.total_score, parallel.total_score);
+            assert_eq!(sequential.breakdown.negative_adjustments, parallel.breakdown.negative_adjustments);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_parallel_time_decay_uses_existing_history() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        let data = create_test_data();
+        engine.calculate_score(data.clone()).unwrap();
+
+        let mut later = data;
+        later.timestamp += 86400;
+        let result = engine.calculate_parallel_scores(vec![later]).remove(0).unwrap();
+        assert!((result.breakdown.time_decay_factor - 0.95).abs() < 1e-9);
+        assert_eq!(engine.get_score_history("test_account").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_chunked_parallel_matches_per_item() {
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let batch: Vec<ChainData> = (0..2_000u32)
+            .map(|i| ChainData { governance_votes: i % 200, community_posts: i % 50, ..create_test_data() })
+            .collect();
+
+        let per_item = engine.calculate_parallel_scores(batch.clone());
+        let chunked = engine.calculate_parallel_scores_chunked(&batch, 64, None);
+
+        assert_eq!(per_item.len(), chunked.len());
+        assert!(per_item.iter().any(|r| r.is_ok()));
+        for (per_item, chunked) in per_item.iter().zip(&chunked) {
+            assert_eq!(per_item.as_ref().map(|r| r.total_score), chunked.as_ref().map(|r| r.total_score));
+        }
+    }
+
+    // Manual-only wall-clock check, kept out of the regular suite because
+    // timings depend on the machine; run it in release builds with
+    // `cargo test --release -- --ignored test_chunked_parallel_not_slower`.
+    // Each variant is warmed up and timed by its best of five runs, so the
+    // chunked path may be at most 10% slower than the per-item one.
+    #[test]
+    #[ignore]
+    #[cfg(feature = "std")]
+    fn test_chunked_parallel_not_slower() {
+        use std::time::{Duration, Instant};
+
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let batch: Vec<ChainData> = (0..100_000).map(|_| create_test_data()).collect();
+
+        let best_of = |run: &dyn Fn() -> usize| -> Duration {
+            run();
+            (0..5).map(|_| {
+                let start = Instant::now();
+                assert_eq!(run(), batch.len());
+                start.elapsed()
+            }).min().unwrap()
+        };
+
+        let per_item_time = best_of(&|| engine.calculate_parallel_scores(batch.clone()).len());
+        let chunked_time = best_of(&|| engine.calculate_parallel_scores_chunked(&batch, 1024, None).len());
+
+        assert!(chunked_time <= per_item_time + per_item_time / 10,
+                "chunked {:?} vs per-item {:?}", chunked_time, per_item_time);
+    }
+
+    #[test]
+    fn test_fixed_point_helpers() {
+        assert_eq!(fixed_ln(1), 0);
+        for x in [2u64, 10, 86400, 1_000_000_000_000, u64::MAX] {
+            let expected = (x as f64).ln() * FIXED_POINT_SCALE as f64;
+            assert!((fixed_ln(x) as f64 - expected).abs() < 2.0, "ln({})", x);
+        }
+
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128 * u64::MAX as u128), u64::MAX as u128);
+
+        assert_eq!(fixed_pow(950_000, 0), FIXED_POINT_SCALE);
+        assert_eq!(fixed_pow(950_000, 2), 902_500);
+        assert_eq!(fixed_pow(2_000_000, 3), FIXED_POINT_SCALE);
+        assert_eq!(to_fixed(0.3), 300_000);
+        assert_eq!(to_fixed(-1.0), 0);
+    }
+
+    #[test]
+    fn test_fixed_point_matches_f64() {
+        let float_config = ScoringConfig { time_decay_enabled: false, ..ScoringConfig::default() };
+        let fixed_config = ScoringConfig { fixed_point: true, ..float_config.clone() };
+        let float_engine = ScoringEngine::new(float_config);
+        let fixed_engine = ScoringEngine::new(fixed_config);
+
+        let mut records = vec![create_test_data()];
+        let mut sparse = create_test_data();
+        sparse.governance_votes = 3;
+        sparse.staking_amount = 12345;
+        sparse.staking_duration = 86400 * 7 + 1234;
+        sparse.identity_verified = false;
+        sparse.community_upvotes = 7;
+        records.push(sparse);
+        let mut judged = create_test_data();
+        judged.identity_judgement_levels = vec![JudgementLevel::KnownGood, JudgementLevel::Erroneous];
+        records.push(judged);
+
+        for data in &records {
+            let float = float_engine.score_once(data).unwrap();
+            let fixed = fixed_engine.score_once(data).unwrap();
+            assert!(float.fixed_score.is_none());
+
+            let units = fixed.fixed_score.unwrap();
+            assert_eq!(fixed.total_score, units as f64 / FIXED_POINT_SCALE as f64);
+            assert!((float.total_score - fixed.total_score).abs() < 1e-4,
+                    "{} vs {}", float.total_score, fixed.total_score);
+            assert!((float.staking_score - fixed.staking_score).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_time_decay_whole_days() {
+        let config = ScoringConfig { fixed_point: true, ..ScoringConfig::default() };
+        let mut engine = ScoringEngine::new(config);
+        let data = create_test_data();
+        let first = engine.calculate_score(data.clone()).unwrap();
+
+        // 1.5 days later: only the whole day counts
+        let mut later = data;
+        later.timestamp += 86400 + 43200;
+        let second = engine.calculate_score(later).unwrap();
+        assert_eq!(second.breakdown.time_decay_factor, 0.95);
+        assert!(second.fixed_score.unwrap() < first.fixed_score.unwrap());
+    }
+
+    #[test]
+    fn test_min_total_actions() {
+        let mut data = create_test_data();
+        data.governance_votes = 1;
+        data.governance_proposals = 0;
+        data.identity_judgements = 0;
+        data.community_posts = 0;
+        data.community_upvotes = 0;
+        assert_eq!(data.total_actions(), 1);
+
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        assert!(engine.calculate_score(data.clone()).is_ok());
+
+        let config = ScoringConfig { min_total_actions: 5, ..ScoringConfig::default() };
+        let mut engine = ScoringEngine::new(config);
+        assert_eq!(engine.calculate_score(data.clone()).unwrap_err(), "insufficient_activity");
+        assert!(engine.get_score_history("test_account").is_none());
+
+        data.community_posts = 4;
+        assert!(engine.calculate_score(data).is_ok());
+    }
+
+    #[test]
+    fn test_reputation_report() {
+        let mut engine = ScoringEngine::new(ScoringConfig::default());
+        let mut data = create_test_data();
+        data.identity_verified = false;
+        data.identity_judgements = 6;
+        let result = engine.calculate_score(data.clone()).unwrap();
+
+        let report = ReputationReport::builder(&result)
+            .with_anomalies(&data)
+            .with_domain_score("extrinsics", 42.5)
+            .build();
+
+        assert_eq!(report.account_id, "test_account");
+        assert_eq!(report.total_score, result.total_score);
+        assert_eq!(report.tier, ReputationTier::from_score(result.total_score));
+        assert_eq!(report.staking_score, result.staking_score);
+        assert_eq!(report.last_updated, 1699430400);
+        assert_eq!(report.anomalies, vec![String::from("Judgements without verification")]);
+        assert_eq!(report.domain_scores[0].domain, "extrinsics");
+
+        let json = report.to_json().unwrap();
+        let parsed: ReputationReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.tier, report.tier);
+        assert_eq!(parsed.domain_scores, report.domain_scores);
+        assert!(json.contains("\"anomalies\""));
+    }
+
+    #[test]
+    fn test_reputation_tier() {
+        assert_eq!(ReputationTier::from_score(95.0), ReputationTier::Elite);
+        assert_eq!(ReputationTier::from_score(80.0), ReputationTier::High);
+        assert_eq!(ReputationTier::from_score(50.0), ReputationTier::Medium);
+        assert_eq!(ReputationTier::from_score(30.0), ReputationTier::Low);
+        assert_eq!(ReputationTier::from_score(29.9), ReputationTier::Unverified);
+    }
+
+    #[test]
+    fn test_window_anomaly_detector() {
+        let mut detector = WindowAnomalyDetector::new(5, 3.0);
+        let mut data = create_test_data();
+
+        // Stable baseline with a little noise; nothing flagged while filling
+        for votes in [48, 52, 50, 49, 51, 50, 52] {
+            data.governance_votes = votes;
+            assert!(detector.push(&data).is_empty());
+        }
+
+        data.governance_votes = 500;
+        let anomalies = detector.push(&data);
+        assert_eq!(anomalies.len(), 1);
+        assert!(anomalies[0].starts_with("governance_votes 500"));
+
+        // The static thresholds do not consider 500 votes suspicious
+        assert!(DataCleaner::detect_anomalies(&data).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "dotrepute-core")]
+    fn test_metric_data_conversion() {
+        let data = create_test_data();
+        let metric_data = data.to_metric_data();
+        assert_eq!(metric_data.governance_votes, 50);
+        assert_eq!(metric_data.staking_amount, 1000000000000);
+        assert_eq!(metric_data.community_upvotes, 500);
+
+        let round_trip = ChainData::from(metric_data);
+        assert!(round_trip.account_id.is_empty());
+        assert_eq!(round_trip.timestamp, 0);
+        assert_eq!(round_trip.to_metric_data(), metric_data);
+        assert_eq!(round_trip.governance_proposals, data.governance_proposals);
+        assert_eq!(round_trip.staking_duration, data.staking_duration);
+    }
+
+    #[test]
+    fn test_community_downvote_penalty() {
+        let metric = CommunityScoreMetric;
+        let config = ScoringConfig::default();
+        let mut data = create_test_data();
+        data.community_posts = 20;
+        data.community_upvotes = 40;
+
+        let baseline = metric.calculate(&data, &config);
+        assert_eq!(baseline, 40.0);
+
+        // One downvote per four upvotes costs 5 points
+        data.community_downvotes = 10;
+        assert_eq!(metric.calculate(&data, &config), 35.0);
+
+        // Heavily downvoted accounts lose at most the cap
+        data.community_downvotes = 400;
+        assert_eq!(metric.calculate(&data, &config), baseline - DOWNVOTE_PENALTY_CAP);
+        assert!(metric.validate_data(&data).is_ok());
+
+        data.community_downvotes = 2001;
+        assert_eq!(metric.validate_data(&data), Err("Suspicious downvote ratio"));
+    }
+
+    #[test]
+    fn test_downvotes_lower_engine_score() {
+        let engine = ScoringEngine::new(ScoringConfig::default());
+        let mut data = create_test_data();
+        let clean = engine.score_once(&data).unwrap();
+
+        data.community_downvotes = 1000;
+        let downvoted = engine.score_once(&data).unwrap();
+        assert!(downvoted.community_score < clean.community_score);
+        assert!(downvoted.total_score < clean.total_score);
+
+        let fixed = ScoringEngine::new(ScoringConfig { fixed_point: true, ..ScoringConfig::default() });
+        let fixed_score = fixed.score_once(&data).unwrap();
+        assert!((fixed_score.community_score - downvoted.community_score).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_governance_scoring_params() {
+        let metric = GovernanceScoreMetric;
+        let mut data = create_test_data();
+        data.governance_votes = 10;
+        data.governance_proposals = 4;
+
+        // Defaults keep 2 points per vote and 5 per proposal
+        let config = ScoringConfig::default();
+        assert_eq!(metric.calculate(&data, &config), 20.0 + 20.0);
+
+        data.governance_proposals_approved = 2;
+        assert_eq!(metric.calculate(&data, &config), 20.0 + 30.0);
+        assert!(metric.validate_data(&data).is_ok());
+
+        let params = GovernanceScoringParams { vote_points: 1.0, proposal_points: 3.0, approved_proposal_bonus: 20.0 };
+        let config = ScoringConfig { governance_params: params, ..ScoringConfig::default() };
+        // Proposal and approval points share the 50 point cap
+        assert_eq!(metric.calculate(&data, &config), 10.0 + 50.0);
+
+        let fixed = ScoringEngine::new(ScoringConfig { fixed_point: true, ..config.clone() });
+        assert!((fixed.score_once(&data).unwrap().governance_score - 60.0).abs() < 1e-6);
+
+        data.governance_proposals_approved = 5;
+        assert_eq!(metric.validate_data(&data), Err("More approved proposals than proposals"));
+    }
+
     #[test]
     fn test_config_update() {
         let mut engine = ScoringEngine::new(ScoringConfig::default());
@@ -1,22 +1,64 @@
 //! Cryptographic utilities for hashing and checksums
 
+use crate::{Error, Result};
+use blake2::{Blake2s256, Digest};
+
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
 /// Simple hash function for data
 pub fn simple_hash(input: &[u8]) -> [u8; 32] {
-    let mut hash = [0u8; 32];
-    
-    for (i, &byte) in input.iter().enumerate() {
-        hash[i % 32] ^= byte;
-        hash[i % 32] = hash[i % 32].wrapping_add(byte);
+    let mut hasher = Hasher::new();
+    hasher.update(input);
+    hasher.finalize()
+}
+
+/// Incremental form of [`simple_hash`]
+///
+/// Feeding data through any number of [`Hasher::update`] calls yields the
+/// same digest as [`simple_hash`] on the concatenated input, without
+/// buffering it.
+#[derive(Debug, Clone)]
+pub struct Hasher {
+    state: [u8; 32],
+    position: usize,
+}
+
+impl Hasher {
+    /// Create a hasher with no input
+    pub fn new() -> Self {
+        Self {
+            state: [0u8; 32],
+            position: 0,
+        }
     }
-    
-    for i in 0..32 {
-        hash[i] = hash[i].wrapping_mul(31).wrapping_add(17);
+
+    /// Absorb the next chunk of input
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            let slot = &mut self.state[self.position % 32];
+            *slot ^= byte;
+            *slot = slot.wrapping_add(byte);
+            self.position = self.position.wrapping_add(1);
+        }
+    }
+
+    /// Finish hashing and return the digest
+    pub fn finalize(self) -> [u8; 32] {
+        let mut hash = self.state;
+
+        for byte in hash.iter_mut() {
+            *byte = byte.wrapping_mul(31).wrapping_add(17);
+        }
+
+        hash
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
     }
-    
-    hash
 }
 
 /// Calculate checksum for data
@@ -28,6 +70,94 @@ pub fn checksum(data: &[u8]) -> u32 {
         })
 }
 
+/// FNV-1a 64-bit offset basis
+const FNV64_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime
+const FNV64_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a checksum of the data
+///
+/// Unlike [`checksum`], every byte is mixed into the full 64-bit state, so
+/// large or reordered inputs do not collide systematically. Accidental
+/// collisions are expected only after about 2^32 distinct inputs (birthday
+/// bound). FNV-1a is not cryptographic: an attacker can construct
+/// collisions cheaply, so use a cryptographic hash such as BLAKE2 where
+/// inputs are adversarial.
+pub fn checksum64(data: &[u8]) -> u64 {
+    data.iter().fold(FNV64_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV64_PRIME)
+    })
+}
+
+/// Map an account ID to a bucket in `0..num_buckets`
+///
+/// Built on [`checksum`], so the result depends only on the ID's bytes and
+/// is stable across runs and platforms. Not suitable where bucket choice
+/// must resist manipulation. Returns `InvalidInput` if `num_buckets` is 0.
+pub fn account_bucket(account_id: &str, num_buckets: u32) -> Result<u32> {
+    if num_buckets == 0 {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(checksum(account_id.as_bytes()) % num_buckets)
+}
+
+/// Block size of BLAKE2s in bytes, used to pad HMAC keys
+const HMAC_BLOCK_LEN: usize = 64;
+
+/// HMAC-BLAKE2s-256 of `message` under `key` (RFC 2104 construction)
+///
+/// Keys longer than the 64-byte block are hashed first; shorter keys are
+/// zero-padded. Output matches Python's `hmac.new(key, message, hashlib.blake2s)`.
+pub fn hmac(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block_key = [0u8; HMAC_BLOCK_LEN];
+    if key.len() > HMAC_BLOCK_LEN {
+        block_key[..32].copy_from_slice(&Blake2s256::digest(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; HMAC_BLOCK_LEN];
+    let mut opad = [0x5cu8; HMAC_BLOCK_LEN];
+    for ((i, o), k) in ipad.iter_mut().zip(opad.iter_mut()).zip(block_key.iter()) {
+        *i ^= k;
+        *o ^= k;
+    }
+
+    let mut inner = Blake2s256::new();
+    inner.update(ipad);
+    inner.update(message);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Blake2s256::new();
+    outer.update(opad);
+    outer.update(inner_hash);
+    outer.finalize().into()
+}
+
+/// Check an [`hmac`] tag in constant time
+pub fn hmac_verify(key: &[u8], message: &[u8], tag: &[u8]) -> bool {
+    ct_eq(&hmac(key, message), tag)
+}
+
+/// Compare two byte slices in constant time
+///
+/// Intended for comparing MACs, hashes and checksums where an early-exit
+/// comparison could leak how many leading bytes matched. Only a length
+/// mismatch returns early.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    
+    let diff = a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (&x, &y)| acc | (x ^ y));
+    
+    diff == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,10 +169,119 @@ mod tests {
         assert_eq!(hash.len(), 32);
     }
 
+    #[test]
+    fn test_hasher_matches_simple_hash() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 + 3) as u8).collect();
+        let expected = simple_hash(&data);
+
+        for chunk_size in [1, 5, 31, 32, 33, 999, 1000] {
+            let mut hasher = Hasher::new();
+            for chunk in data.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected, "chunk size {}", chunk_size);
+        }
+
+        let mut hasher = Hasher::default();
+        hasher.update(b"");
+        assert_eq!(hasher.finalize(), simple_hash(b""));
+    }
+
     #[test]
     fn test_checksum() {
         let data = b"test";
         let sum = checksum(data);
         assert!(sum > 0);
     }
+
+    #[test]
+    fn test_checksum64_reference_vectors() {
+        // Reference values from the FNV specification's test suite
+        assert_eq!(checksum64(b""), 0xcbf29ce484222325);
+        assert_eq!(checksum64(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(checksum64(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_checksum64_order_sensitive() {
+        // Same bytes and length, different order
+        assert_ne!(checksum64(b"ab"), checksum64(b"ba"));
+        assert_ne!(checksum64(&[0u8; 1024]), checksum64(&[0u8; 1025]));
+    }
+
+    #[test]
+    fn test_account_bucket() {
+        // checksum("alice") = 97 + 108*2 + 105*3 + 99*4 + 101*5 = 1529
+        assert_eq!(account_bucket("alice", 10), Ok(9));
+        assert_eq!(account_bucket("alice", 1), Ok(0));
+        assert_eq!(account_bucket("alice", 0), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_account_bucket_distribution() {
+        let mut counts = [0u32; 16];
+        for i in 0..10_000 {
+            let id = format!("account-{}", i);
+            counts[account_bucket(&id, 16).unwrap() as usize] += 1;
+        }
+
+        // 625 per bucket if perfectly even
+        for &count in &counts {
+            assert!((500..=750).contains(&count), "uneven buckets: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_hmac_reference_vectors() {
+        // Expected values from Python's hmac module with hashlib.blake2s
+        let tag = hmac(b"key", b"The quick brown fox jumps over the lazy dog");
+        assert_eq!(
+            crate::encoding::hex_encode(&tag),
+            "f93215bb90d4af4c3061cd932fb169fb8bb8a91d0b4022baea1271e1323cd9a0"
+        );
+
+        let tag = hmac(&[0x0b; 20], b"Hi There");
+        assert_eq!(
+            crate::encoding::hex_encode(&tag),
+            "65a8b7c5cc9136d424e82c37e2707e74e913c0655b99c75f40edf387453a3260"
+        );
+
+        // Key longer than the block size is hashed first
+        let tag = hmac(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First");
+        assert_eq!(
+            crate::encoding::hex_encode(&tag),
+            "d23d79394f53d536a096e6514447eeaabb05ded01be32c1937da6a8f7103bc4e"
+        );
+    }
+
+    #[test]
+    fn test_hmac_verify() {
+        let message = serde_json::to_vec(&crate::scoring::WeightConfig::default()).unwrap();
+        let tag = hmac(b"secret", &message);
+
+        assert!(hmac_verify(b"secret", &message, &tag));
+        assert!(!hmac_verify(b"other", &message, &tag));
+        assert!(!hmac_verify(b"secret", b"tampered", &tag));
+        assert!(!hmac_verify(b"secret", &message, &tag[..31]));
+    }
+
+    #[test]
+    fn test_ct_eq_equal() {
+        let a = simple_hash(b"test");
+        let b = simple_hash(b"test");
+        assert!(ct_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_ct_eq_unequal() {
+        let a = simple_hash(b"test");
+        let b = simple_hash(b"tess");
+        assert!(!ct_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_ct_eq_length_mismatch() {
+        assert!(!ct_eq(b"test", b"tes"));
+        assert!(!ct_eq(b"", b"a"));
+    }
 }
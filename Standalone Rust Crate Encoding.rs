@@ -1,44 +1,171 @@
 //! Data encoding and decoding utilities
 
 use crate::{Error, Result};
+use scale::{Decode, DecodeAll, Encode};
 
 #[cfg(not(feature = "std"))]
 use alloc::{vec::Vec, string::String};
 
+/// Letter case used for hexadecimal digits a-f
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexCase {
+    /// Lowercase digits (`deadbeef`)
+    Lower,
+    /// Uppercase digits (`DEADBEEF`)
+    Upper,
+}
+
 /// Encode bytes to hexadecimal string
 pub fn hex_encode(bytes: &[u8]) -> String {
-    let mut hex = String::new();
+    hex_encode_cased(bytes, HexCase::Lower)
+}
+
+/// Encode bytes to hexadecimal string using the given letter case
+pub fn hex_encode_cased(bytes: &[u8], case: HexCase) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
     
     for &byte in bytes {
-        hex.push(nibble_to_char((byte >> 4) & 0x0f));
-        hex.push(nibble_to_char(byte & 0x0f));
+        hex.push(nibble_to_char((byte >> 4) & 0x0f, case));
+        hex.push(nibble_to_char(byte & 0x0f, case));
     }
     
     hex
 }
 
-/// Decode hexadecimal string to bytes
+/// Encode bytes to a 0x-prefixed hexadecimal string
+pub fn hex_encode_prefixed(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    hex.push_str(&hex_encode(bytes));
+    hex
+}
+
+/// Decode hexadecimal string to bytes, with or without a 0x prefix
 pub fn hex_decode(hex: &str) -> Result<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    
     if hex.len() % 2 != 0 {
         return Err(Error::EncodingError);
     }
     
-    let mut bytes = Vec::new();
-    let chars: Vec<char> = hex.chars().collect();
+    let mut bytes = Vec::with_capacity(hex.len() / 2);
     
-    for i in (0..chars.len()).step_by(2) {
-        let high = char_to_nibble(chars[i])?;
-        let low = char_to_nibble(chars[i + 1])?;
+    // Work on raw bytes so multi-byte characters cannot misalign the pairs
+    for pair in hex.as_bytes().chunks(2) {
+        let high = char_to_nibble(pair[0] as char)?;
+        let low = char_to_nibble(pair[1] as char)?;
         bytes.push((high << 4) | low);
     }
     
     Ok(bytes)
 }
 
-fn nibble_to_char(nibble: u8) -> char {
+/// Bitcoin base58 alphabet, as used by SS58 addresses
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encode bytes to a base58 string
+pub fn base58_encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    
+    // Base58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    
+    let mut result = String::with_capacity(zeros + digits.len());
+    for _ in 0..zeros {
+        result.push('1');
+    }
+    for &digit in digits.iter().rev() {
+        result.push(BASE58_ALPHABET[digit as usize] as char);
+    }
+    
+    result
+}
+
+/// Decode a base58 string to bytes
+pub fn base58_decode(s: &str) -> Result<Vec<u8>> {
+    let zeros = s.chars().take_while(|&c| c == '1').count();
+    
+    // Decoded bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    
+    for c in s.chars().skip(zeros) {
+        let mut carry = base58_char_value(c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    
+    let mut result = Vec::with_capacity(zeros + bytes.len());
+    result.resize(zeros, 0);
+    result.extend(bytes.iter().rev());
+    
+    Ok(result)
+}
+
+/// Encode a value to SCALE bytes for on-chain storage
+pub fn scale_encode<T: Encode>(value: &T) -> Vec<u8> {
+    value.encode()
+}
+
+/// Decode a value from SCALE bytes
+///
+/// The whole input must be consumed; trailing bytes are rejected.
+pub fn scale_decode<T: Decode>(bytes: &[u8]) -> Result<T> {
+    T::decode_all(&mut &bytes[..]).map_err(|_| Error::EncodingError)
+}
+
+/// Serialize a value to CBOR, a compact binary alternative to JSON
+#[cfg(feature = "cbor")]
+pub fn to_cbor<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(value, &mut bytes).map_err(|_| Error::EncodingError)?;
+    Ok(bytes)
+}
+
+/// Deserialize a value from CBOR bytes
+#[cfg(feature = "cbor")]
+pub fn from_cbor<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    ciborium::de::from_reader(bytes).map_err(|_| Error::EncodingError)
+}
+
+fn base58_char_value(c: char) -> Result<u8> {
+    if !c.is_ascii() {
+        return Err(Error::EncodingError);
+    }
+    
+    BASE58_ALPHABET.iter()
+        .position(|&a| a == c as u8)
+        .map(|p| p as u8)
+        .ok_or(Error::EncodingError)
+}
+
+fn nibble_to_char(nibble: u8, case: HexCase) -> char {
+    let alpha = match case {
+        HexCase::Lower => b'a',
+        HexCase::Upper => b'A',
+    };
+    
     match nibble {
         0..=9 => (b'0' + nibble) as char,
-        10..=15 => (b'a' + nibble - 10) as char,
+        10..=15 => (alpha + nibble - 10) as char,
         _ => '0',
     }
 }
@@ -61,9 +188,128 @@ mod tests {
         assert_eq!(hex_encode(&[0xde, 0xad]), "dead");
     }
 
+    #[test]
+    fn test_scale_round_trip() {
+        use crate::scoring::WeightConfig;
+
+        let config = WeightConfig::default();
+        let bytes = scale_encode(&config);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(scale_decode::<WeightConfig>(&bytes), Ok(config));
+    }
+
+    #[test]
+    fn test_scale_decode_invalid() {
+        use crate::scoring::WeightConfig;
+
+        let bytes = scale_encode(&WeightConfig::default());
+        assert_eq!(scale_decode::<WeightConfig>(&bytes[..8]), Err(Error::EncodingError));
+
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(scale_decode::<WeightConfig>(&extended), Err(Error::EncodingError));
+    }
+
+    #[test]
+    #[cfg(feature = "cbor")]
+    fn test_cbor_round_trip() {
+        use crate::scoring::{ScoreCalculator, ScoreResult, Tier};
+
+        let data = crate::scoring::MetricData {
+            governance_votes: 50,
+            governance_proposals: 5,
+            staking_amount: 1_000_000,
+            staking_duration: 2_592_000,
+            identity_verified: true,
+            identity_judgements: 2,
+            community_posts: 100,
+            community_upvotes: 500,
+        };
+        let score = ScoreCalculator::new().calculate(&data).unwrap();
+
+        let bytes = to_cbor(&score).unwrap();
+        assert_eq!(from_cbor::<ScoreResult>(&bytes), Ok(score));
+        assert!(bytes.len() < serde_json::to_vec(&score).unwrap().len());
+
+        assert_eq!(from_cbor::<Tier>(&to_cbor(&Tier::High).unwrap()), Ok(Tier::High));
+    }
+
+    #[test]
+    #[cfg(feature = "cbor")]
+    fn test_cbor_decode_invalid() {
+        use crate::scoring::ScoreResult;
+
+        assert_eq!(from_cbor::<ScoreResult>(&[]), Err(Error::EncodingError));
+        assert_eq!(from_cbor::<ScoreResult>(&[0xff, 0x00, 0x13]), Err(Error::EncodingError));
+        // Well-formed CBOR of the wrong shape
+        let bytes = to_cbor(&"not a score").unwrap();
+        assert_eq!(from_cbor::<ScoreResult>(&bytes), Err(Error::EncodingError));
+    }
+
     #[test]
     fn test_hex_decode() {
         let result = hex_decode("dead").unwrap();
         assert_eq!(result, vec![0xde, 0xad]);
     }
+
+    #[test]
+    fn test_hex_encode_cased() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(hex_encode_cased(&bytes, HexCase::Lower), "deadbeef");
+        assert_eq!(hex_encode_cased(&bytes, HexCase::Upper), "DEADBEEF");
+    }
+
+    #[test]
+    fn test_hex_decode_case_insensitive() {
+        let expected = vec![0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(hex_decode("DEADBEEF").unwrap(), expected);
+        assert_eq!(hex_decode("deadbeef").unwrap(), expected);
+        assert_eq!(hex_decode("DeAdBeEf").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_hex_decode_invalid() {
+        assert_eq!(hex_decode("xyz"), Err(Error::EncodingError));
+        assert_eq!(hex_decode("abc"), Err(Error::EncodingError));
+        assert_eq!(hex_decode("zz"), Err(Error::EncodingError));
+        assert_eq!(hex_decode("\u{00e9}"), Err(Error::EncodingError));
+    }
+
+    #[test]
+    fn test_hex_encode_prefixed() {
+        assert_eq!(hex_encode_prefixed(&[0xde, 0xad, 0xbe, 0xef]), "0xdeadbeef");
+        assert_eq!(hex_encode_prefixed(&[]), "0x");
+    }
+
+    #[test]
+    fn test_hex_decode_optional_prefix() {
+        let expected = vec![0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(hex_decode("deadbeef").unwrap(), expected);
+        assert_eq!(hex_decode("0xdeadbeef").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_base58_encode() {
+        assert_eq!(base58_encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(base58_encode(&[0, 0, 1]), "112");
+        assert_eq!(base58_encode(&[]), "");
+    }
+
+    #[test]
+    fn test_base58_roundtrip() {
+        let inputs: [&[u8]; 4] = [b"hello world", &[0, 0, 0xff, 0x10], &[0], &[0xde, 0xad, 0xbe, 0xef]];
+        for input in inputs.iter() {
+            let encoded = base58_encode(input);
+            assert_eq!(base58_decode(&encoded).unwrap(), input.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_base58_decode_invalid_char() {
+        // '0', 'O', 'I' and 'l' are excluded from the alphabet
+        assert_eq!(base58_decode("0abc"), Err(Error::EncodingError));
+        assert_eq!(base58_decode("abcO"), Err(Error::EncodingError));
+        assert_eq!(base58_decode("Il"), Err(Error::EncodingError));
+        assert_eq!(base58_decode("ab\u{00e9}"), Err(Error::EncodingError));
+    }
 }
@@ -0,0 +1,252 @@
+//! Identity parsing and verification utilities
+
+use crate::{Error, Result};
+use crate::encoding::base58_decode;
+use blake2::{Blake2b512, Digest};
+use serde::{Deserialize, Serialize};
+use scale::{Decode, Encode};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+/// Prefix hashed together with the payload to derive the SS58 checksum
+const SS58_PREFIX: &[u8] = b"SS58PRE";
+
+/// Length of an SS58 checksum for 32-byte public keys
+const SS58_CHECKSUM_LEN: usize = 2;
+
+/// Maximum length of a sanitized display name in bytes, matching the
+/// 32-byte raw data limit of on-chain identity fields
+pub const MAX_DISPLAY_NAME_LEN: usize = 32;
+
+/// SS58 network prefix for Polkadot
+pub const POLKADOT_PREFIX: u8 = 0;
+
+/// SS58 network prefix for Kusama
+pub const KUSAMA_PREFIX: u8 = 2;
+
+/// SS58 network prefix for generic Substrate chains
+pub const SUBSTRATE_PREFIX: u8 = 42;
+
+/// Decode an SS58 address into its network prefix and 32-byte public key
+///
+/// Only single-byte network prefixes (0-63) are supported.
+pub fn decode_ss58(address: &str) -> Result<(u8, [u8; 32])> {
+    let data = base58_decode(address.trim())?;
+
+    if data.len() != 1 + 32 + SS58_CHECKSUM_LEN {
+        return Err(Error::InvalidInput);
+    }
+
+    let prefix = data[0];
+    if prefix >= 64 {
+        return Err(Error::InvalidInput);
+    }
+
+    let (payload, checksum) = data.split_at(1 + 32);
+    let hash = ss58_hash(payload);
+    if hash[..SS58_CHECKSUM_LEN] != *checksum {
+        return Err(Error::ValidationFailed);
+    }
+
+    let mut public_key = [0u8; 32];
+    public_key.copy_from_slice(&payload[1..]);
+
+    Ok((prefix, public_key))
+}
+
+/// Method used to verify an identity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[cfg_attr(feature = "substrate", derive(scale_info::TypeInfo))]
+pub enum IdentityType {
+    /// Basic wallet verification
+    Wallet,
+    /// Email verification
+    Email,
+    /// Phone verification
+    Phone,
+    /// Government ID verification
+    GovernmentId,
+    /// Biometric verification
+    Biometric,
+    /// Social media verification
+    SocialMedia,
+}
+
+impl IdentityType {
+    /// Confidence points contributed by the verification method
+    pub fn method_weight(&self) -> u32 {
+        match self {
+            IdentityType::Biometric => 55,
+            IdentityType::GovernmentId => 50,
+            IdentityType::Phone => 30,
+            IdentityType::Email => 25,
+            IdentityType::SocialMedia => 20,
+            IdentityType::Wallet => 10,
+        }
+    }
+}
+
+/// Confidence points per registrar judgement
+pub const JUDGEMENT_POINTS: u32 = 10;
+
+/// Maximum confidence points from registrar judgements
+pub const MAX_JUDGEMENT_POINTS: u32 = 30;
+
+/// Confidence bonus for a completed verification
+pub const VERIFIED_BONUS: u32 = 10;
+
+/// Derive a 0-100 identity confidence from verification facts
+///
+/// The method weight and the verified bonus only count once the identity is
+/// verified; judgements count either way, up to [`MAX_JUDGEMENT_POINTS`].
+pub fn confidence_from(identity_type: IdentityType, judgements: u32, verified: bool) -> u32 {
+    let judgement_points = judgements.saturating_mul(JUDGEMENT_POINTS).min(MAX_JUDGEMENT_POINTS);
+
+    let verification_points = if verified {
+        identity_type.method_weight() + VERIFIED_BONUS
+    } else {
+        0
+    };
+
+    (verification_points + judgement_points).min(100)
+}
+
+/// Check whether the input is a well-formed SS58 address with a valid checksum
+pub fn validate_identity_format(input: &str) -> bool {
+    decode_ss58(input).is_ok()
+}
+
+/// Check whether the input is a valid SS58 address for the given network prefix
+pub fn validate_for_network(address: &str, expected_prefix: u8) -> bool {
+    matches!(decode_ss58(address), Ok((prefix, _)) if prefix == expected_prefix)
+}
+
+/// Turn raw on-chain display name bytes into a clean string
+///
+/// Rejects invalid UTF-8, removes control characters and surrounding
+/// whitespace, and returns `Error::InvalidInput` if the result is empty or
+/// longer than [`MAX_DISPLAY_NAME_LEN`] bytes.
+pub fn sanitize_display_name(raw: &[u8]) -> Result<String> {
+    let text = core::str::from_utf8(raw).map_err(|_| Error::InvalidInput)?;
+
+    let cleaned: String = text.chars().filter(|c| !c.is_control()).collect();
+    let name = cleaned.trim();
+
+    if name.is_empty() || name.len() > MAX_DISPLAY_NAME_LEN {
+        return Err(Error::InvalidInput);
+    }
+
+    Ok(name.into())
+}
+
+fn ss58_hash(payload: &[u8]) -> [u8; 64] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(SS58_PREFIX);
+    hasher.update(payload);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    const ALICE_POLKADOT: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+    const ALICE_KUSAMA: &str = "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F";
+
+    #[test]
+    fn test_decode_ss58() {
+        let (prefix, public_key) = decode_ss58(ALICE).unwrap();
+        assert_eq!(prefix, 42);
+        assert_eq!(
+            crate::encoding::hex_encode(&public_key),
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+        );
+    }
+
+    #[test]
+    fn test_decode_ss58_bad_checksum() {
+        // Last character altered, payload length unchanged
+        let tampered = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ";
+        assert_eq!(decode_ss58(tampered), Err(Error::ValidationFailed));
+    }
+
+    #[test]
+    fn test_validate_identity_format() {
+        assert!(validate_identity_format(ALICE));
+        assert!(!validate_identity_format("invalid"));
+        assert!(!validate_identity_format("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"));
+        assert!(!validate_identity_format("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKut0Y"));
+    }
+
+    #[test]
+    fn test_validate_for_network() {
+        assert!(validate_for_network(ALICE_POLKADOT, POLKADOT_PREFIX));
+        assert!(validate_for_network(ALICE_KUSAMA, KUSAMA_PREFIX));
+        assert!(validate_for_network(ALICE, SUBSTRATE_PREFIX));
+
+        // Same key, wrong network
+        assert!(!validate_for_network(ALICE_KUSAMA, POLKADOT_PREFIX));
+        assert!(!validate_for_network(ALICE_POLKADOT, KUSAMA_PREFIX));
+        assert!(!validate_for_network(ALICE, POLKADOT_PREFIX));
+
+        assert!(!validate_for_network("invalid", POLKADOT_PREFIX));
+    }
+
+    #[test]
+    fn test_decode_ss58_network_prefixes() {
+        let (_, generic_key) = decode_ss58(ALICE).unwrap();
+        assert_eq!(decode_ss58(ALICE_POLKADOT).unwrap(), (POLKADOT_PREFIX, generic_key));
+        assert_eq!(decode_ss58(ALICE_KUSAMA).unwrap(), (KUSAMA_PREFIX, generic_key));
+    }
+
+    #[test]
+    fn test_sanitize_display_name() {
+        assert_eq!(sanitize_display_name(b"  Alice\n").unwrap(), "Alice");
+        assert_eq!(sanitize_display_name(b"Al\x00i\x1bce").unwrap(), "Alice");
+        assert_eq!(sanitize_display_name("Gavin Wood \u{1F980}".as_bytes()).unwrap(), "Gavin Wood \u{1F980}");
+
+        assert_eq!(sanitize_display_name(b"\xff\xfeAlice"), Err(Error::InvalidInput));
+        assert_eq!(sanitize_display_name(b" \t\r\n "), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_sanitize_display_name_length() {
+        let longest = [b'a'; MAX_DISPLAY_NAME_LEN];
+        assert_eq!(sanitize_display_name(&longest).unwrap().len(), MAX_DISPLAY_NAME_LEN);
+
+        let overlong = [b'a'; MAX_DISPLAY_NAME_LEN + 1];
+        assert_eq!(sanitize_display_name(&overlong), Err(Error::InvalidInput));
+
+        // Limit is in bytes: 11 three-byte characters are 33 bytes
+        assert_eq!(sanitize_display_name("\u{20AC}".repeat(11).as_bytes()), Err(Error::InvalidInput));
+
+        // Stripped characters do not count toward the limit
+        let mut padded = [b' '; MAX_DISPLAY_NAME_LEN + 8];
+        padded[4..4 + MAX_DISPLAY_NAME_LEN].copy_from_slice(&longest);
+        assert!(sanitize_display_name(&padded).is_ok());
+    }
+
+    #[test]
+    fn test_confidence_from_identity_types() {
+        assert_eq!(confidence_from(IdentityType::Biometric, 3, true), 95);
+        assert_eq!(confidence_from(IdentityType::GovernmentId, 3, true), 90);
+        assert_eq!(confidence_from(IdentityType::Phone, 3, true), 70);
+        assert_eq!(confidence_from(IdentityType::Email, 3, true), 65);
+        assert_eq!(confidence_from(IdentityType::SocialMedia, 3, true), 60);
+        assert_eq!(confidence_from(IdentityType::Wallet, 3, true), 50);
+    }
+
+    #[test]
+    fn test_confidence_from_judgements_and_verification() {
+        assert_eq!(confidence_from(IdentityType::GovernmentId, 0, true), 60);
+        assert_eq!(confidence_from(IdentityType::GovernmentId, 1, true), 70);
+        // Judgement points are capped
+        assert_eq!(confidence_from(IdentityType::GovernmentId, u32::MAX, true), 90);
+
+        // Unverified identities only earn judgement points
+        assert_eq!(confidence_from(IdentityType::Wallet, 0, false), 0);
+        assert_eq!(confidence_from(IdentityType::Biometric, 2, false), 20);
+    }
+}
@@ -1,18 +1,75 @@
 //! Time-related utilities
 
+use core::fmt::Write;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 /// Seconds per day constant
 pub const SECONDS_PER_DAY: u64 = 86400;
 
+/// Seconds per hour constant
+pub const SECONDS_PER_HOUR: u64 = 3600;
+
+/// Seconds per minute constant
+pub const SECONDS_PER_MINUTE: u64 = 60;
+
 /// Convert days to seconds
 pub fn days_to_seconds(days: u64) -> u64 {
     days.saturating_mul(SECONDS_PER_DAY)
 }
 
-/// Convert seconds to days
+/// Convert seconds to whole days (inverse of [`days_to_seconds`])
 pub fn seconds_to_days(seconds: u64) -> u64 {
     seconds / SECONDS_PER_DAY
 }
 
+/// Format a duration as a human-readable string such as `2d 3h 15m 10s`
+///
+/// Leading zero units are omitted, so `70` becomes `1m 10s` and `0` becomes `0s`.
+pub fn format_duration(secs: u64) -> String {
+    let units = [
+        (secs / SECONDS_PER_DAY, 'd'),
+        ((secs % SECONDS_PER_DAY) / SECONDS_PER_HOUR, 'h'),
+        ((secs % SECONDS_PER_HOUR) / SECONDS_PER_MINUTE, 'm'),
+        (secs % SECONDS_PER_MINUTE, 's'),
+    ];
+    
+    let first = units.iter()
+        .position(|&(value, _)| value > 0)
+        .unwrap_or(units.len() - 1);
+    
+    let mut result = String::new();
+    for (value, suffix) in &units[first..] {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        let _ = write!(result, "{}{}", value, suffix);
+    }
+    
+    result
+}
+
+/// Estimate the wall-clock timestamp of a block from its height
+///
+/// Assumes a constant block time since `genesis_time`.
+pub fn estimate_timestamp(block: u32, genesis_time: u64, block_time_secs: u64) -> u64 {
+    genesis_time.saturating_add((block as u64).saturating_mul(block_time_secs))
+}
+
+/// Estimate the block height produced at a given timestamp
+///
+/// Inverse of [`estimate_timestamp`]. Timestamps before genesis map to block 0
+/// and heights beyond `u32::MAX` saturate.
+pub fn estimate_block(timestamp: u64, genesis_time: u64, block_time_secs: u64) -> u32 {
+    if block_time_secs == 0 {
+        return 0;
+    }
+    
+    let elapsed = timestamp.saturating_sub(genesis_time);
+    (elapsed / block_time_secs).min(u32::MAX as u64) as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,5 +82,31 @@ mod tests {
     #[test]
     fn test_seconds_to_days() {
         assert_eq!(seconds_to_days(172800), 2);
+        assert_eq!(seconds_to_days(days_to_seconds(30)), 30);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(SECONDS_PER_DAY), "1d 0h 0m 0s");
+        assert_eq!(format_duration(70), "1m 10s");
+        assert_eq!(format_duration(2 * SECONDS_PER_DAY + 3 * SECONDS_PER_HOUR + 15 * 60 + 10), "2d 3h 15m 10s");
+    }
+
+    #[test]
+    fn test_estimate_timestamp() {
+        assert_eq!(estimate_timestamp(0, 1_000, 6), 1_000);
+        assert_eq!(estimate_timestamp(100, 1_000, 6), 1_600);
+        assert_eq!(estimate_timestamp(u32::MAX, u64::MAX - 1, 6), u64::MAX);
+    }
+
+    #[test]
+    fn test_estimate_block() {
+        assert_eq!(estimate_block(1_600, 1_000, 6), 100);
+        assert_eq!(estimate_block(1_605, 1_000, 6), 100);
+        assert_eq!(estimate_block(500, 1_000, 6), 0);
+        assert_eq!(estimate_block(1_600, 1_000, 0), 0);
+        assert_eq!(estimate_block(u64::MAX, 0, 1), u32::MAX);
+        assert_eq!(estimate_block(estimate_timestamp(12_345, 1_000, 6), 1_000, 6), 12_345);
     }
 }
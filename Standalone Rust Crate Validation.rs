@@ -15,6 +15,62 @@ pub fn validate_range(value: u64, min: u64, max: u64) -> Result<()> {
     Ok(())
 }
 
+/// Return `value` if it lies within `[lo, hi]`
+///
+/// Values outside the bounds (including NaN) yield `Error::OutOfRange`;
+/// bounds with `lo > hi` yield `Error::InvalidInput`.
+pub fn in_range<T: PartialOrd>(value: T, lo: T, hi: T) -> Result<T> {
+    if lo > hi {
+        return Err(Error::InvalidInput);
+    }
+    if value >= lo && value <= hi {
+        Ok(value)
+    } else {
+        Err(Error::OutOfRange)
+    }
+}
+
+/// Clamp `value` into `[lo, hi]`
+///
+/// Expects `lo <= hi`. Unlike `Ord::clamp` this works for any `PartialOrd`
+/// type and never panics; a NaN input is returned unchanged.
+pub fn clamp_to_range<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+    if value < lo {
+        lo
+    } else if value > hi {
+        hi
+    } else {
+        value
+    }
+}
+
+/// Check that a record timestamp is plausible relative to `now`
+///
+/// Fails with `Error::ValidationFailed` when the timestamp is more than
+/// `max_future_skew` seconds ahead of `now` (clock drift or tampering), and
+/// with `Error::OutOfRange` when it is more than `max_age` seconds old.
+pub fn check_freshness(timestamp: u64, now: u64, max_future_skew: u64, max_age: u64) -> Result<()> {
+    if timestamp > now {
+        if timestamp - now > max_future_skew {
+            return Err(Error::ValidationFailed);
+        }
+    } else if now - timestamp > max_age {
+        return Err(Error::OutOfRange);
+    }
+    Ok(())
+}
+
+/// Validate every row, returning the index and error of each failing row
+///
+/// Rows are checked independently, so one bad row does not stop the rest
+/// from being validated. An empty result means the whole batch is valid.
+pub fn validate_batch<T>(rows: &[T], validator: impl Fn(&T) -> Result<()>) -> Vec<(usize, Error)> {
+    rows.iter()
+        .enumerate()
+        .filter_map(|(index, row)| validator(row).err().map(|error| (index, error)))
+        .collect()
+}
+
 /// Clean and normalize text
 pub fn normalize_text(input: &str) -> String {
     input.trim().to_lowercase()
@@ -41,6 +97,63 @@ mod tests {
         assert!(validate_range(150, 0, 100).is_err());
     }
 
+    #[test]
+    fn test_in_range() {
+        assert_eq!(in_range(0u32, 0, 10), Ok(0));
+        assert_eq!(in_range(10u32, 0, 10), Ok(10));
+        assert_eq!(in_range(11u32, 0, 10), Err(Error::OutOfRange));
+        assert_eq!(in_range(u64::MAX, 0, u64::MAX), Ok(u64::MAX));
+        assert_eq!(in_range(-0.5f64, 0.0, 1.0), Err(Error::OutOfRange));
+        assert_eq!(in_range(1.0f64, 0.0, 1.0), Ok(1.0));
+        assert_eq!(in_range(f64::NAN, 0.0, 1.0), Err(Error::OutOfRange));
+        assert_eq!(in_range(5u32, 10, 0), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_clamp_to_range() {
+        assert_eq!(clamp_to_range(5u32, 0, 10), 5);
+        assert_eq!(clamp_to_range(0u32, 1, 10), 1);
+        assert_eq!(clamp_to_range(u64::MAX, 0, 100), 100);
+        assert_eq!(clamp_to_range(-1.5f64, 0.0, 1.0), 0.0);
+        assert_eq!(clamp_to_range(1.0f64, 0.0, 1.0), 1.0);
+        assert!(clamp_to_range(f64::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_check_freshness() {
+        let now = 1_699_430_400;
+        let skew = 300;
+        let max_age = 365 * 86_400;
+
+        assert!(check_freshness(now, now, skew, max_age).is_ok());
+        assert!(check_freshness(now + skew, now, skew, max_age).is_ok());
+        assert!(check_freshness(now - max_age, now, skew, max_age).is_ok());
+
+        // Future-dated records
+        assert_eq!(check_freshness(now + skew + 1, now, skew, max_age), Err(Error::ValidationFailed));
+        assert_eq!(check_freshness(u64::MAX, now, skew, max_age), Err(Error::ValidationFailed));
+
+        // Ancient records
+        assert_eq!(check_freshness(now - max_age - 1, now, skew, max_age), Err(Error::OutOfRange));
+        assert_eq!(check_freshness(0, now, skew, max_age), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        let rows = [50u64, 150, 0, 100, 101];
+        let errors = validate_batch(&rows, |&value| validate_range(value, 0, 100));
+        assert_eq!(errors, [(1, Error::OutOfRange), (4, Error::OutOfRange)]);
+
+        let names = ["alice", "", "bob"];
+        let errors = validate_batch(&names, |name| {
+            if name.is_empty() { Err(Error::InvalidInput) } else { Ok(()) }
+        });
+        assert_eq!(errors, [(1, Error::InvalidInput)]);
+
+        assert!(validate_batch(&[1u64, 2, 3], |&value| validate_range(value, 0, 10)).is_empty());
+        assert!(validate_batch(&[] as &[u64], |_| Err(Error::InvalidInput)).is_empty());
+    }
+
     #[test]
     fn test_normalize_text() {
         assert_eq!(normalize_text("  Hello World  "), "hello world");
@@ -33,6 +33,13 @@ fn main() {
             println!("Community Score:   {}/100", result.community_score);
             println!("Total Score:       {}", result.total_score);
             println!("Weighted Score:    {}/100\n", result.weighted_score);
+
+            let shares = result.breakdown_percentages();
+            println!("=== Score Breakdown ===");
+            println!("Governance:  {:.1}%", shares.governance);
+            println!("Staking:     {:.1}%", shares.staking);
+            println!("Identity:    {:.1}%", shares.identity);
+            println!("Community:   {:.1}%\n", shares.community);
         }
         Err(e) => eprintln!("Error calculating score: {}", e),
     }
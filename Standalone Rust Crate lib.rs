@@ -19,13 +19,22 @@
 //! let calculator = ScoreCalculator::new();
 //! let data = MetricData {
 //!     governance_votes: 50,
-//!     staking_amount: 1000000,
+//!     governance_proposals: 5,
+//!     staking_amount: 1_000_000,
+//!     staking_duration: 2_592_000,
 //!     identity_verified: true,
+//!     identity_judgements: 2,
 //!     community_posts: 100,
+//!     community_upvotes: 500,
 //! };
 //! 
-//! let score = calculator.calculate(&data);
-//! println!("Reputation Score: {}", score.total);
+//! let score = calculator.calculate(&data).unwrap();
+//! let shares = score.breakdown_percentages();
+//! println!("Reputation Score: {}", score.weighted_score);
+//! println!("Governance: {} ({:.1}%)", score.governance_score, shares.governance);
+//! println!("Staking:    {} ({:.1}%)", score.staking_score, shares.staking);
+//! println!("Identity:   {} ({:.1}%)", score.identity_score, shares.identity);
+//! println!("Community:  {} ({:.1}%)", score.community_score, shares.community);
 //! ```
 //! 
 //! # Modules
@@ -42,6 +51,7 @@
 //! - `std` (default): Enable standard library support
 //! - `wasm`: Enable WebAssembly bindings
 //! - `substrate`: Enable Substrate/Polkadot integration
+//! - `cbor`: Enable CBOR serialization in [`encoding`]
 //! - `full`: Enable all features
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -74,6 +74,43 @@ pub struct ScoreResult {
     pub weighted_score: u64,
 }
 
+/// Share of the total score contributed by each component, in percent
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScorePercentages {
+    /// Governance share of the total (0-100)
+    pub governance: f64,
+    /// Staking share of the total (0-100)
+    pub staking: f64,
+    /// Identity share of the total (0-100)
+    pub identity: f64,
+    /// Community share of the total (0-100)
+    pub community: f64,
+}
+
+impl ScoreResult {
+    /// Each component's share of `total_score`, in percent
+    ///
+    /// Returns all zeros when the total is zero.
+    pub fn breakdown_percentages(&self) -> ScorePercentages {
+        if self.total_score == 0 {
+            return ScorePercentages {
+                governance: 0.0,
+                staking: 0.0,
+                identity: 0.0,
+                community: 0.0,
+            };
+        }
+
+        let total = self.total_score as f64;
+        ScorePercentages {
+            governance: self.governance_score as f64 * 100.0 / total,
+            staking: self.staking_score as f64 * 100.0 / total,
+            identity: self.identity_score as f64 * 100.0 / total,
+            community: self.community_score as f64 * 100.0 / total,
+        }
+    }
+}
+
 /// Configuration for score calculation weights
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
 #[cfg_attr(feature = "substrate", derive(scale_info::TypeInfo))]
@@ -115,6 +152,82 @@ impl WeightConfig {
     }
 }
 
+/// Reputation tier labels shared by the off-chain scorer and on-chain registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[cfg_attr(feature = "substrate", derive(scale_info::TypeInfo))]
+pub enum Tier {
+    /// Top contributors
+    Elite,
+    /// Strong, sustained participation
+    High,
+    /// Regular participation
+    Medium,
+    /// Some verified activity
+    Low,
+    /// Below the lowest threshold
+    Unverified,
+}
+
+/// Minimum total score required for each tier
+///
+/// The defaults line up with the registry contract's role thresholds (80/50/30).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TierConfig {
+    /// Minimum score for [`Tier::Elite`]
+    pub elite_threshold: f64,
+    /// Minimum score for [`Tier::High`]
+    pub high_threshold: f64,
+    /// Minimum score for [`Tier::Medium`]
+    pub medium_threshold: f64,
+    /// Minimum score for [`Tier::Low`]
+    pub low_threshold: f64,
+}
+
+impl Default for TierConfig {
+    fn default() -> Self {
+        Self {
+            elite_threshold: 90.0,
+            high_threshold: 80.0,
+            medium_threshold: 50.0,
+            low_threshold: 30.0,
+        }
+    }
+}
+
+impl TierConfig {
+    /// Validate that thresholds are strictly descending
+    pub fn validate(&self) -> Result<()> {
+        if self.elite_threshold > self.high_threshold
+            && self.high_threshold > self.medium_threshold
+            && self.medium_threshold > self.low_threshold
+        {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput)
+        }
+    }
+
+    /// Classify a total score into a tier using these thresholds
+    pub fn classify(&self, total_score: f64) -> Tier {
+        if total_score >= self.elite_threshold {
+            Tier::Elite
+        } else if total_score >= self.high_threshold {
+            Tier::High
+        } else if total_score >= self.medium_threshold {
+            Tier::Medium
+        } else if total_score >= self.low_threshold {
+            Tier::Low
+        } else {
+            Tier::Unverified
+        }
+    }
+}
+
+/// Classify a total score into a tier using the default thresholds
+pub fn classify_tier(total_score: f64) -> Tier {
+    TierConfig::default().classify(total_score)
+}
+
 /// Main score calculator
 pub struct ScoreCalculator {
     weights: WeightConfig,
@@ -302,4 +415,81 @@ mod tests {
         
         assert_eq!(result.staking_score, 0);
     }
+
+    #[test]
+    fn test_breakdown_percentages() {
+        let calculator = ScoreCalculator::new();
+        let result = calculator.calculate(&sample_data()).unwrap();
+        let percentages = result.breakdown_percentages();
+        
+        let sum = percentages.governance
+            + percentages.staking
+            + percentages.identity
+            + percentages.community;
+        assert!((sum - 100.0).abs() < 1e-9);
+        
+        let expected_identity = result.identity_score as f64 * 100.0 / result.total_score as f64;
+        assert_eq!(percentages.identity, expected_identity);
+    }
+
+    #[test]
+    fn test_breakdown_percentages_zero_total() {
+        let data = MetricData {
+            governance_votes: 0,
+            governance_proposals: 0,
+            staking_amount: 0,
+            staking_duration: 0,
+            identity_verified: false,
+            identity_judgements: 0,
+            community_posts: 0,
+            community_upvotes: 0,
+        };
+        
+        let result = ScoreCalculator::new().calculate(&data).unwrap();
+        let percentages = result.breakdown_percentages();
+        
+        assert_eq!(result.total_score, 0);
+        assert_eq!(percentages.governance, 0.0);
+        assert_eq!(percentages.community, 0.0);
+    }
+
+    #[test]
+    fn test_classify_tier_boundaries() {
+        assert_eq!(classify_tier(100.0), Tier::Elite);
+        assert_eq!(classify_tier(90.0), Tier::Elite);
+        assert_eq!(classify_tier(89.99), Tier::High);
+        assert_eq!(classify_tier(80.0), Tier::High);
+        assert_eq!(classify_tier(79.99), Tier::Medium);
+        assert_eq!(classify_tier(50.0), Tier::Medium);
+        assert_eq!(classify_tier(49.99), Tier::Low);
+        assert_eq!(classify_tier(30.0), Tier::Low);
+        assert_eq!(classify_tier(29.99), Tier::Unverified);
+        assert_eq!(classify_tier(0.0), Tier::Unverified);
+    }
+
+    #[test]
+    fn test_custom_tier_config() {
+        let config = TierConfig {
+            elite_threshold: 75.0,
+            high_threshold: 60.0,
+            medium_threshold: 40.0,
+            low_threshold: 10.0,
+        };
+        
+        assert!(config.validate().is_ok());
+        assert_eq!(config.classify(75.0), Tier::Elite);
+        assert_eq!(config.classify(10.0), Tier::Low);
+        assert_eq!(config.classify(9.0), Tier::Unverified);
+    }
+
+    #[test]
+    fn test_tier_config_validation() {
+        let config = TierConfig {
+            high_threshold: 95.0,
+            ..TierConfig::default()
+        };
+        
+        assert!(TierConfig::default().validate().is_ok());
+        assert_eq!(config.validate(), Err(Error::InvalidInput));
+    }
 }
@@ -2,6 +2,11 @@
 //! 
 //! Exports high-performance utility functions for browser and serverless environments.
 //! Compiled to WebAssembly for near-native performance in web applications.
+//!
+//! JavaScript callers should invoke `init_panic_hook()` once at startup so that
+//! any unexpected panic is reported to the browser console instead of surfacing
+//! as an opaque `unreachable` trap. Recoverable failures (overflow, empty keys,
+//! malformed input) are returned as thrown JS errors.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -11,12 +16,16 @@ extern crate alloc;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+#[cfg(feature = "wasm")]
+use serde::Serialize;
+
 #[cfg(not(feature = "std"))]
-use alloc::{vec::Vec, string::String};
+use alloc::{vec::Vec, string::{String, ToString}};
 
 #[cfg(feature = "std")]
 use std::{vec::Vec, string::String};
 
+pub mod error;
 pub mod score_normalizer;
 pub mod data_cleaner;
 pub mod identity_parser;
@@ -24,6 +33,21 @@ pub mod crypto_utils;
 pub mod math_helpers;
 pub mod time_utils;
 pub mod encoding;
+pub mod scoring_engine;
+
+pub use error::Error;
+
+#[cfg(feature = "wasm")]
+fn to_js_error(e: Error) -> JsValue {
+    JsValue::from_str(&e.to_string())
+}
+
+/// Forwards Rust panic messages to `console.error`. Safe to call more than once.
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn init_panic_hook() {
+    console_error_panic_hook::set_once();
+}
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
@@ -32,6 +56,8 @@ pub fn normalize_score_js(value: f64, min: f64, max: f64) -> f64 {
         min_value: min,
         max_value: max,
         scale_factor: 1.0,
+        mode: score_normalizer::NormalizationMode::Linear,
+        clamp_output: true,
     };
     score_normalizer::normalize_score(value, &config)
 }
@@ -56,14 +82,26 @@ pub fn hash_data_js(input: Vec<u8>) -> Vec<u8> {
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
-pub fn calculate_pow_js(base: u32, exp: u32) -> u32 {
-    math_helpers::fast_pow(base as u64, exp) as u32
+pub fn calculate_pow_js(base: u32, exp: u32) -> Result<u32, JsValue> {
+    u32::try_from(math_helpers::fast_pow(base as u64, exp))
+        .map_err(|_| to_js_error(Error::OutOfRange))
 }
 
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
-pub fn days_to_seconds_js(days: u32) -> u32 {
-    time_utils::days_to_seconds(days as u64) as u32
+pub fn mod_pow_js(base: u32, exp: u32, modulus: u32) -> Result<u32, JsValue> {
+    if modulus == 0 {
+        return Err(to_js_error(Error::DivisionByZero));
+    }
+    // The result is below a u32 modulus, so the conversion is lossless
+    Ok(math_helpers::mod_pow(base as u64, exp as u64, modulus as u64) as u32)
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn days_to_seconds_js(days: u32) -> Result<u32, JsValue> {
+    u32::try_from(time_utils::days_to_seconds(days as u64))
+        .map_err(|_| to_js_error(Error::OutOfRange))
 }
 
 #[cfg(feature = "wasm")]
@@ -72,6 +110,12 @@ pub fn hex_encode_js(bytes: Vec<u8>) -> String {
     encoding::hex_encode(&bytes)
 }
 
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn hex_encode_prefixed_js(bytes: Vec<u8>) -> String {
+    encoding::hex_encode_prefixed(&bytes)
+}
+
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub fn hex_decode_js(hex: String) -> Result<Vec<u8>, JsValue> {
@@ -97,11 +141,61 @@ pub fn gcd_js(a: u32, b: u32) -> u32 {
     math_helpers::gcd(a as u64, b as u64) as u32
 }
 
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn lcm_js(a: u32, b: u32) -> Result<u32, JsValue> {
+    math_helpers::lcm(a as u64, b as u64)
+        .and_then(|lcm| u32::try_from(lcm).ok())
+        .ok_or_else(|| to_js_error(Error::OutOfRange))
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn score_chain_data_js(json: String) -> Result<String, JsValue> {
+    let data: scoring_engine::ChainData = serde_json::from_str(&json)
+        .map_err(|_| JsValue::from_str("Invalid ChainData JSON"))?;
+    
+    let engine = scoring_engine::ScoringEngine::new(scoring_engine::ScoringConfig::default());
+    let result = engine.score_once(&data)
+        .map_err(JsValue::from_str)?;
+    
+    serde_json::to_string(&result)
+        .map_err(|_| JsValue::from_str("JSON serialization failed"))
+}
+
+#[cfg(feature = "wasm")]
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BatchScoreEntry {
+    Ok(scoring_engine::ScoreResult),
+    Err(String),
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub fn score_batch_js(json_array: String) -> Result<String, JsValue> {
+    let batch: Vec<scoring_engine::ChainData> = serde_json::from_str(&json_array)
+        .map_err(|_| JsValue::from_str("Invalid ChainData array JSON"))?;
+    
+    let mut engine = scoring_engine::ScoringEngine::new(scoring_engine::ScoringConfig::default());
+    let entries: Vec<BatchScoreEntry> = engine.calculate_batch_scores(batch)
+        .into_iter()
+        .map(|result| match result {
+            Ok(score) => BatchScoreEntry::Ok(score),
+            Err(e) => BatchScoreEntry::Err(String::from(e)),
+        })
+        .collect();
+    
+    serde_json::to_string(&entries)
+        .map_err(|_| JsValue::from_str("JSON serialization failed"))
+}
+
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub struct WasmScoreNormalizer {
     min_value: f64,
     max_value: f64,
+    mode: score_normalizer::NormalizationMode,
 }
 
 #[cfg(feature = "wasm")]
@@ -112,25 +206,83 @@ impl WasmScoreNormalizer {
         Self {
             min_value: min,
             max_value: max,
+            mode: score_normalizer::NormalizationMode::Linear,
         }
     }
 
+    pub fn with_mode(min: f64, max: f64, mode: String) -> Result<WasmScoreNormalizer, JsValue> {
+        let mode = score_normalizer::NormalizationMode::from_name(&mode)
+            .ok_or_else(|| JsValue::from_str("Unknown normalization mode"))?;
+        
+        Ok(Self {
+            min_value: min,
+            max_value: max,
+            mode,
+        })
+    }
+
     pub fn normalize(&self, value: f64) -> f64 {
-        let config = score_normalizer::NormalizationConfig {
-            min_value: self.min_value,
-            max_value: self.max_value,
-            scale_factor: 1.0,
-        };
-        score_normalizer::normalize_score(value, &config)
+        score_normalizer::normalize_score(value, &self.config())
     }
 
     pub fn denormalize(&self, value: f64) -> f64 {
-        let config = score_normalizer::NormalizationConfig {
+        score_normalizer::denormalize_score(value, &self.config())
+    }
+
+    fn config(&self) -> score_normalizer::NormalizationConfig {
+        score_normalizer::NormalizationConfig {
             min_value: self.min_value,
             max_value: self.max_value,
             scale_factor: 1.0,
+            mode: self.mode,
+            clamp_output: true,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub struct WasmScoringEngine {
+    engine: scoring_engine::ScoringEngine,
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl WasmScoringEngine {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        governance_weight: f64,
+        staking_weight: f64,
+        identity_weight: f64,
+        community_weight: f64,
+    ) -> Self {
+        let config = scoring_engine::ScoringConfig {
+            governance_weight,
+            staking_weight,
+            identity_weight,
+            community_weight,
+            ..scoring_engine::ScoringConfig::default()
         };
-        score_normalizer::denormalize_score(value, &config)
+        
+        Self {
+            engine: scoring_engine::ScoringEngine::new(config),
+        }
+    }
+
+    pub fn score(&mut self, json: String) -> Result<String, JsValue> {
+        let data: scoring_engine::ChainData = serde_json::from_str(&json)
+            .map_err(|_| JsValue::from_str("Invalid ChainData JSON"))?;
+        
+        let result = self.engine.calculate_score(data)
+            .map_err(JsValue::from_str)?;
+        
+        serde_json::to_string(&result)
+            .map_err(|_| JsValue::from_str("JSON serialization failed"))
+    }
+
+    pub fn get_history_json(&self, account_id: String) -> Result<String, JsValue> {
+        self.engine.export_history_json(&account_id)
+            .map_err(JsValue::from_str)
     }
 }
 
@@ -150,12 +302,14 @@ impl WasmCryptoHelper {
         crypto_utils::simple_hash(&data).to_vec()
     }
 
-    pub fn encrypt(&self, data: Vec<u8>, key: Vec<u8>) -> Vec<u8> {
+    pub fn encrypt(&self, data: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, JsValue> {
         crypto_utils::xor_encrypt(&data, &key)
+            .map_err(to_js_error)
     }
 
-    pub fn decrypt(&self, data: Vec<u8>, key: Vec<u8>) -> Vec<u8> {
+    pub fn decrypt(&self, data: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, JsValue> {
         crypto_utils::xor_decrypt(&data, &key)
+            .map_err(to_js_error)
     }
 
     pub fn checksum(&self, data: Vec<u8>) -> u32 {
@@ -211,4 +365,124 @@ mod wasm_tests {
         let result = hex_encode_js(data);
         assert_eq!(result, "deadbeef");
     }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_hex_encode_prefixed() {
+        let data = vec![0xde, 0xad, 0xbe, 0xef];
+        let result = hex_encode_prefixed_js(data);
+        assert_eq!(result, "0xdeadbeef");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_overflow_is_error() {
+        init_panic_hook();
+        assert_eq!(calculate_pow_js(2, 10).unwrap(), 1024);
+        assert!(calculate_pow_js(2, 32).is_err());
+        assert_eq!(days_to_seconds_js(1).unwrap(), 86400);
+        assert!(days_to_seconds_js(u32::MAX).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_mod_pow() {
+        assert_eq!(mod_pow_js(3, 200, 50).unwrap(), 1);
+        assert_eq!(mod_pow_js(u32::MAX, u32::MAX, u32::MAX - 1).unwrap(), 1);
+        assert!(mod_pow_js(3, 200, 0).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_lcm() {
+        assert_eq!(lcm_js(4, 6).unwrap(), 12);
+        assert_eq!(lcm_js(0, 6).unwrap(), 0);
+        assert!(lcm_js(u32::MAX, u32::MAX - 1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_score_chain_data() {
+        let json = r#"{
+            "account_id": "test_account",
+            "governance_votes": 50,
+            "governance_proposals": 5,
+            "staking_amount": 1000000000000,
+            "staking_duration": 2592000,
+            "identity_verified": true,
+            "identity_judgements": 2,
+            "community_posts": 100,
+            "community_upvotes": 500,
+            "timestamp": 1699430400
+        }"#;
+        
+        let output = score_chain_data_js(json.to_string()).unwrap();
+        let result: scoring_engine::ScoreResult = serde_json::from_str(&output).unwrap();
+        assert_eq!(result.account_id, "test_account");
+        assert!(result.total_score > 0.0);
+        
+        assert!(score_chain_data_js("not json".to_string()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_score_batch() {
+        let json = r#"[
+            {
+                "account_id": "valid_account",
+                "governance_votes": 50,
+                "governance_proposals": 5,
+                "staking_amount": 1000000000000,
+                "staking_duration": 2592000,
+                "identity_verified": true,
+                "identity_judgements": 2,
+                "community_posts": 100,
+                "community_upvotes": 500,
+                "timestamp": 1699430400
+            },
+            {
+                "account_id": "invalid_account",
+                "governance_votes": 20000,
+                "governance_proposals": 5,
+                "staking_amount": 1000000000000,
+                "staking_duration": 2592000,
+                "identity_verified": true,
+                "identity_judgements": 2,
+                "community_posts": 100,
+                "community_upvotes": 500,
+                "timestamp": 1699430400
+            }
+        ]"#;
+        
+        let output = score_batch_js(json.to_string()).unwrap();
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&output).unwrap();
+        
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["ok"]["account_id"], "valid_account");
+        assert_eq!(entries[1]["err"], "Unrealistic governance votes count");
+        
+        assert!(score_batch_js("{}".to_string()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_scoring_engine_history() {
+        let mut engine = WasmScoringEngine::new(0.25, 0.25, 0.25, 0.25);
+        assert!(engine.get_history_json("test_account".to_string()).is_err());
+        
+        let json = r#"{
+            "account_id": "test_account",
+            "governance_votes": 50,
+            "governance_proposals": 5,
+            "staking_amount": 1000000000000,
+            "staking_duration": 2592000,
+            "identity_verified": true,
+            "identity_judgements": 2,
+            "community_posts": 100,
+            "community_upvotes": 500,
+            "timestamp": 1699430400
+        }"#;
+        
+        engine.score(json.to_string()).unwrap();
+        engine.score(json.to_string()).unwrap();
+        
+        let history = engine.get_history_json("test_account".to_string()).unwrap();
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&history).unwrap();
+        assert_eq!(entries.len(), 2);
+        
+        assert!(engine.score("not json".to_string()).is_err());
+    }
 }
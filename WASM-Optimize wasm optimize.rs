@@ -14,14 +14,88 @@ use alloc::{vec::Vec, string::String};
 #[cfg(feature = "std")]
 use std::{vec::Vec, string::String};
 
+pub mod error {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Error {
+        InvalidInput,
+        ValidationFailed,
+        CalculationError,
+        EncodingError,
+        CryptoError,
+        OutOfRange,
+        DivisionByZero,
+    }
+
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Error::InvalidInput => write!(f, "Invalid input data"),
+                Error::ValidationFailed => write!(f, "Validation failed"),
+                Error::CalculationError => write!(f, "Score calculation error"),
+                Error::EncodingError => write!(f, "Encoding/decoding error"),
+                Error::CryptoError => write!(f, "Cryptographic operation failed"),
+                Error::OutOfRange => write!(f, "Value out of range"),
+                Error::DivisionByZero => write!(f, "Division by zero"),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for Error {}
+}
+
+pub use error::Error;
+
 pub mod score_normalizer {
     use super::*;
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum NormalizationMode {
+        #[default]
+        Linear,
+        Logarithmic,
+        Sqrt,
+    }
+
+    impl NormalizationMode {
+        pub fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "linear" => Some(NormalizationMode::Linear),
+                "log" | "logarithmic" => Some(NormalizationMode::Logarithmic),
+                "sqrt" => Some(NormalizationMode::Sqrt),
+                _ => None,
+            }
+        }
+
+        // Values <= 0 have no meaningful log/sqrt and map to 0
+        fn apply(self, value: f64) -> f64 {
+            match self {
+                NormalizationMode::Linear => value,
+                NormalizationMode::Logarithmic => {
+                    if value <= 0.0 { 0.0 } else { value.ln_1p() }
+                }
+                NormalizationMode::Sqrt => {
+                    if value <= 0.0 { 0.0 } else { value.sqrt() }
+                }
+            }
+        }
+
+        fn invert(self, value: f64) -> f64 {
+            match self {
+                NormalizationMode::Linear => value,
+                NormalizationMode::Logarithmic => value.exp_m1(),
+                NormalizationMode::Sqrt => value * value,
+            }
+        }
+    }
+
     #[derive(Debug, Clone, Copy)]
     pub struct NormalizationConfig {
         pub min_value: f64,
         pub max_value: f64,
         pub scale_factor: f64,
+        pub mode: NormalizationMode,
+        pub clamp_output: bool,
     }
 
     impl Default for NormalizationConfig {
@@ -30,25 +104,63 @@ pub mod score_normalizer {
                 min_value: 0.0,
                 max_value: 100.0,
                 scale_factor: 1.0,
+                mode: NormalizationMode::Linear,
+                clamp_output: true,
             }
         }
     }
 
+    /// Maps `value` from `[min_value, max_value]` onto `[0, scale_factor]`.
+    ///
+    /// `denormalize_score` is the exact inverse for any in-range value and any
+    /// non-zero `scale_factor`. For `Logarithmic` and `Sqrt` this additionally
+    /// requires `min_value >= 0`, since all non-positive inputs map to 0.
+    /// With `clamp_output` disabled, out-of-range inputs are extrapolated.
     pub fn normalize_score(value: f64, config: &NormalizationConfig) -> f64 {
-        let clamped = clamp(value, config.min_value, config.max_value);
-        let range = config.max_value - config.min_value;
+        let clamped = if config.clamp_output {
+            clamp(value, config.min_value, config.max_value)
+        } else {
+            value
+        };
+        let low = config.mode.apply(config.min_value);
+        let range = config.mode.apply(config.max_value) - low;
         
         if range == 0.0 {
-            return config.min_value;
+            return 0.0;
         }
         
-        ((clamped - config.min_value) / range) * config.scale_factor
+        ((config.mode.apply(clamped) - low) / range) * config.scale_factor
     }
 
+    /// Maps a value from `[0, scale_factor]` back onto `[min_value, max_value]`.
+    ///
+    /// Inverse of `normalize_score`; degenerate configs (empty range or zero
+    /// `scale_factor`) map everything to `min_value`.
     pub fn denormalize_score(normalized: f64, config: &NormalizationConfig) -> f64 {
-        let range = config.max_value - config.min_value;
-        let value = (normalized / config.scale_factor) * range + config.min_value;
-        clamp(value, config.min_value, config.max_value)
+        let low = config.mode.apply(config.min_value);
+        let range = config.mode.apply(config.max_value) - low;
+        
+        if range == 0.0 || config.scale_factor == 0.0 {
+            return config.min_value;
+        }
+        
+        let value = config.mode.invert((normalized / config.scale_factor) * range + low);
+        
+        if config.clamp_output {
+            clamp(value, config.min_value, config.max_value)
+        } else {
+            value
+        }
+    }
+
+    /// Like `normalize_score`, but reports inputs outside `[min_value, max_value]`
+    /// as `Error::OutOfRange` when `clamp_output` is disabled.
+    pub fn normalize_score_checked(value: f64, config: &NormalizationConfig) -> Result<f64, Error> {
+        if !config.clamp_output && (value < config.min_value || value > config.max_value) {
+            return Err(Error::OutOfRange);
+        }
+        
+        Ok(normalize_score(value, config))
     }
 
     pub fn normalize_batch(values: &[f64], config: &NormalizationConfig) -> Vec<f64> {
@@ -99,6 +211,109 @@ pub mod score_normalizer {
             assert_eq!(denormalize_score(0.5, &config), 50.0);
         }
 
+        #[test]
+        fn test_logarithmic_mode() {
+            let config = NormalizationConfig {
+                min_value: 0.0,
+                max_value: 1_000_000.0,
+                scale_factor: 1.0,
+                mode: NormalizationMode::Logarithmic,
+                clamp_output: true,
+            };
+            
+            assert_eq!(normalize_score(0.0, &config), 0.0);
+            assert_eq!(normalize_score(-5.0, &config), 0.0);
+            assert_eq!(normalize_score(1_000_000.0, &config), 1.0);
+            // A thousand is half way to a million on a log scale
+            assert!((normalize_score(1_000.0, &config) - 0.5).abs() < 0.01);
+        }
+
+        #[test]
+        fn test_sqrt_mode() {
+            let config = NormalizationConfig {
+                mode: NormalizationMode::Sqrt,
+                ..NormalizationConfig::default()
+            };
+            
+            assert_eq!(normalize_score(25.0, &config), 0.5);
+            assert_eq!(denormalize_score(0.5, &config), 25.0);
+        }
+
+        #[test]
+        fn test_denormalize_inverts_normalize() {
+            let modes = [
+                NormalizationMode::Linear,
+                NormalizationMode::Logarithmic,
+                NormalizationMode::Sqrt,
+            ];
+            let scales = [1.0, 0.01, 7.5, 100.0, 1_000.0];
+            
+            for &mode in &modes {
+                for &scale_factor in &scales {
+                    let config = NormalizationConfig {
+                        min_value: 0.0,
+                        max_value: 1_000.0,
+                        scale_factor,
+                        mode,
+                        clamp_output: true,
+                    };
+                    
+                    for i in 0..=1_000 {
+                        let x = i as f64 * 0.999;
+                        let roundtrip = denormalize_score(normalize_score(x, &config), &config);
+                        assert!(
+                            (roundtrip - x).abs() <= 1e-9 * x.max(1.0),
+                            "{:?} scale {}: {} -> {}", mode, scale_factor, x, roundtrip
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_degenerate_config() {
+            let config = NormalizationConfig {
+                min_value: 10.0,
+                max_value: 10.0,
+                ..NormalizationConfig::default()
+            };
+            
+            assert_eq!(normalize_score(10.0, &config), 0.0);
+            assert_eq!(denormalize_score(0.0, &config), 10.0);
+            
+            let config = NormalizationConfig {
+                scale_factor: 0.0,
+                ..NormalizationConfig::default()
+            };
+            assert_eq!(denormalize_score(0.0, &config), 0.0);
+        }
+
+        #[test]
+        fn test_normalize_score_checked() {
+            let clamped = NormalizationConfig::default();
+            assert_eq!(normalize_score_checked(150.0, &clamped), Ok(1.0));
+            assert_eq!(normalize_score_checked(-10.0, &clamped), Ok(0.0));
+            
+            let unclamped = NormalizationConfig {
+                clamp_output: false,
+                ..NormalizationConfig::default()
+            };
+            assert_eq!(normalize_score_checked(50.0, &unclamped), Ok(0.5));
+            assert_eq!(normalize_score_checked(100.0, &unclamped), Ok(1.0));
+            assert_eq!(normalize_score_checked(150.0, &unclamped), Err(Error::OutOfRange));
+            assert_eq!(normalize_score_checked(-10.0, &unclamped), Err(Error::OutOfRange));
+            
+            // The unchecked variant extrapolates instead
+            assert_eq!(normalize_score(150.0, &unclamped), 1.5);
+        }
+
+        #[test]
+        fn test_mode_from_name() {
+            assert_eq!(NormalizationMode::from_name("log"), Some(NormalizationMode::Logarithmic));
+            assert_eq!(NormalizationMode::from_name("sqrt"), Some(NormalizationMode::Sqrt));
+            assert_eq!(NormalizationMode::from_name("cubic"), None);
+        }
+
         #[test]
         fn test_z_score_normalize() {
             assert_eq!(z_score_normalize(10.0, 5.0, 2.0), 2.5);
@@ -140,6 +355,117 @@ pub mod data_cleaner {
         input.to_lowercase().trim().to_string()
     }
 
+    pub fn collapse_whitespace(input: &str) -> String {
+        let mut result = String::with_capacity(input.len());
+        let mut in_whitespace = false;
+        
+        for c in input.chars() {
+            if c.is_whitespace() {
+                if !in_whitespace {
+                    result.push(' ');
+                    in_whitespace = true;
+                }
+            } else if !c.is_ascii_control() {
+                result.push(c);
+                in_whitespace = false;
+            }
+        }
+        
+        result
+    }
+
+    pub fn clean_content(input: &str) -> String {
+        normalize_text(&collapse_whitespace(input))
+    }
+
+    // Locale-independent lowercasing followed by NFC composition, so that
+    // precomposed and decomposed forms of the same text compare equal
+    #[cfg(feature = "unicode")]
+    pub fn normalize_text_unicode(input: &str) -> String {
+        use unicode_normalization::UnicodeNormalization;
+        
+        input.trim().to_lowercase().nfc().collect()
+    }
+
+    // Spam heuristic weightings, summing to 1.0:
+    // - capitalization: share of uppercase letters outside URLs above 50%
+    //   (needs 8+ letters)
+    // - repetition: longest run of one character, saturating at 10
+    // - links: URLs per word, saturating at one URL in every four words
+    // - length: very short (< 10 chars) or very long (> 5000 chars) content
+    const SPAM_WEIGHT_CAPS: f64 = 0.30;
+    const SPAM_WEIGHT_REPEAT: f64 = 0.25;
+    const SPAM_WEIGHT_LINKS: f64 = 0.30;
+    const SPAM_WEIGHT_LENGTH: f64 = 0.15;
+
+    pub fn spam_score(text: &str) -> f64 {
+        let is_url = |w: &str| w.starts_with("http://") || w.starts_with("https://") || w.starts_with("www.");
+        
+        let mut length = 0u32;
+        let mut longest_run = 0u32;
+        let mut run = 0u32;
+        let mut previous: Option<char> = None;
+        
+        for c in text.chars() {
+            length += 1;
+            run = if previous == Some(c) && !c.is_whitespace() { run + 1 } else { 1 };
+            longest_run = longest_run.max(run);
+            previous = Some(c);
+        }
+        
+        let mut words = 0u32;
+        let mut urls = 0u32;
+        let mut letters = 0u32;
+        let mut uppercase = 0u32;
+        
+        for word in text.split_whitespace() {
+            words += 1;
+            if is_url(word) {
+                urls += 1;
+                continue;
+            }
+            for c in word.chars().filter(|c| c.is_alphabetic()) {
+                letters += 1;
+                if c.is_uppercase() {
+                    uppercase += 1;
+                }
+            }
+        }
+        
+        let caps = if letters >= 8 {
+            ((uppercase as f64 / letters as f64 - 0.5) / 0.5).max(0.0)
+        } else {
+            0.0
+        };
+        
+        let repeat = if longest_run > 3 {
+            ((longest_run - 3) as f64 / 7.0).min(1.0)
+        } else {
+            0.0
+        };
+        
+        let links = if words > 0 {
+            (urls as f64 / words as f64 * 4.0).min(1.0)
+        } else {
+            0.0
+        };
+        
+        let length_penalty = if length < 10 {
+            (10 - length) as f64 / 10.0
+        } else if length > 5000 {
+            ((length - 5000) as f64 / 5000.0).min(1.0)
+        } else {
+            0.0
+        };
+        
+        let score = caps * SPAM_WEIGHT_CAPS
+            + repeat * SPAM_WEIGHT_REPEAT
+            + links * SPAM_WEIGHT_LINKS
+            + length_penalty * SPAM_WEIGHT_LENGTH;
+        
+        score.clamp(0.0, 1.0)
+    }
+
     pub fn remove_duplicates<T: PartialEq + Clone>(items: &[T]) -> Vec<T> {
         let mut result = Vec::new();
         
@@ -197,6 +523,39 @@ pub mod data_cleaner {
             assert_eq!(normalize_text("  Hello World  "), "hello world");
         }
 
+        #[test]
+        fn test_collapse_whitespace() {
+            assert_eq!(collapse_whitespace("a\t\tb\n\nc"), "a b c");
+            assert_eq!(collapse_whitespace("a \r\n\t b"), "a b");
+            assert_eq!(collapse_whitespace("nul\0byte\x07s"), "nulbytes");
+            assert_eq!(collapse_whitespace("a \0 b"), "a b");
+        }
+
+        #[test]
+        fn test_clean_content() {
+            assert_eq!(clean_content("\0  Great\tProposal\n\n  "), "great proposal");
+            assert_eq!(clean_content("line one\r\nline two"), "line one line two");
+        }
+
+        #[cfg(feature = "unicode")]
+        #[test]
+        fn test_normalize_text_unicode() {
+            // Precomposed and decomposed forms normalize identically
+            assert_eq!(normalize_text_unicode("  Caf\u{00C9} "), "caf\u{00e9}");
+            assert_eq!(normalize_text_unicode("CAFE\u{0301}"), "caf\u{00e9}");
+            assert_eq!(normalize_text_unicode("\u{00C5}ngstr\u{00F6}m"), "\u{00e5}ngstr\u{00f6}m");
+        }
+
+        #[cfg(feature = "unicode")]
+        #[test]
+        fn test_normalize_text_unicode_turkish_i() {
+            // Folding is locale-independent: 'I' always becomes 'i', dotless 'ı'
+            // is preserved, and dotted 'İ' keeps its dot as a combining mark
+            assert_eq!(normalize_text_unicode("ISTANBUL"), "istanbul");
+            assert_eq!(normalize_text_unicode("\u{0131}rmak"), "\u{0131}rmak");
+            assert_eq!(normalize_text_unicode("\u{0130}zmir"), "i\u{0307}zmir");
+        }
+
         #[test]
         fn test_remove_duplicates() {
             let input = vec![1, 2, 2, 3, 3, 3, 4];
@@ -204,6 +563,33 @@ pub mod data_cleaner {
             assert_eq!(result, vec![1, 2, 3, 4]);
         }
 
+        #[test]
+        fn test_spam_score_clean_content() {
+            let post = "I think this referendum strikes a good balance between treasury spend and delivery milestones.";
+            assert_eq!(spam_score(post), 0.0);
+        }
+
+        #[test]
+        fn test_spam_score_spammy_content() {
+            let post = "FREE DOT GIVEAWAY!!!!!!!!!! https://scam.example www.scam.example";
+            let score = spam_score(post);
+            assert!(score > 0.7, "score was {}", score);
+            assert!(score <= 1.0);
+        }
+
+        #[test]
+        fn test_spam_score_individual_signals() {
+            // Shouting alone is a partial signal
+            let shouting = spam_score("THIS PROPOSAL IS GREAT AND SHOULD PASS");
+            assert!((shouting - SPAM_WEIGHT_CAPS).abs() < 1e-9);
+            
+            // Empty content only trips the length heuristic
+            assert!((spam_score("") - SPAM_WEIGHT_LENGTH).abs() < 1e-9);
+            
+            // Deterministic for identical input
+            assert_eq!(spam_score("soooooooo good"), spam_score("soooooooo good"));
+        }
+
         #[test]
         fn test_validate_numeric_range() {
             assert!(validate_numeric_range(50.0, 0.0, 100.0));
@@ -220,6 +606,7 @@ pub mod data_cleaner {
 
 pub mod identity_parser {
     use super::*;
+    use blake2::{Blake2b512, Digest};
 
     #[derive(Debug, Clone)]
     pub struct IdentityData {
@@ -254,12 +641,36 @@ pub mod identity_parser {
         Ok(cleaned.to_string())
     }
 
-    pub fn validate_identity_format(input: &str) -> bool {
-        if input.len() < 32 || input.len() > 64 {
-            return false;
+    pub fn decode_ss58(address: &str) -> Result<(u8, [u8; 32]), Error> {
+        let data = encoding::base58_decode(address.trim())?;
+        
+        if data.len() != 1 + 32 + 2 {
+            return Err(Error::InvalidInput);
+        }
+        
+        let prefix = data[0];
+        if prefix >= 64 {
+            return Err(Error::InvalidInput);
+        }
+        
+        let (payload, checksum) = data.split_at(1 + 32);
+        let mut hasher = Blake2b512::new();
+        hasher.update(b"SS58PRE");
+        hasher.update(payload);
+        let hash = hasher.finalize();
+        
+        if hash[..2] != *checksum {
+            return Err(Error::ValidationFailed);
         }
         
-        input.chars().all(|c| c.is_alphanumeric())
+        let mut public_key = [0u8; 32];
+        public_key.copy_from_slice(&payload[1..]);
+        
+        Ok((prefix, public_key))
+    }
+
+    pub fn validate_identity_format(input: &str) -> bool {
+        decode_ss58(input).is_ok()
     }
 
     pub fn extract_verification_level(score: u64) -> u8 {
@@ -295,6 +706,21 @@ pub mod identity_parser {
             assert!(!validate_identity_format("invalid"));
         }
 
+        #[test]
+        fn test_validate_identity_format_rejects_bad_checksum() {
+            // Alphanumeric and the right length, but the checksum does not match
+            assert!(!validate_identity_format("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"));
+            assert!(!validate_identity_format("abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTU"));
+        }
+
+        #[test]
+        fn test_decode_ss58() {
+            let (prefix, public_key) = decode_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+            assert_eq!(prefix, 42);
+            assert_eq!(public_key[0], 0xd4);
+            assert_eq!(public_key[31], 0x7d);
+        }
+
         #[test]
         fn test_extract_verification_level() {
             assert_eq!(extract_verification_level(25), 0);
@@ -323,18 +749,18 @@ pub mod crypto_utils {
         hash
     }
 
-    pub fn xor_encrypt(data: &[u8], key: &[u8]) -> Vec<u8> {
+    pub fn xor_encrypt(data: &[u8], key: &[u8]) -> Result<Vec<u8>, Error> {
         if key.is_empty() {
-            return data.to_vec();
+            return Err(Error::CryptoError);
         }
         
-        data.iter()
+        Ok(data.iter()
             .enumerate()
             .map(|(i, &byte)| byte ^ key[i % key.len()])
-            .collect()
+            .collect())
     }
 
-    pub fn xor_decrypt(data: &[u8], key: &[u8]) -> Vec<u8> {
+    pub fn xor_decrypt(data: &[u8], key: &[u8]) -> Result<Vec<u8>, Error> {
         xor_encrypt(data, key)
     }
 
@@ -366,12 +792,20 @@ pub mod crypto_utils {
             let data = b"secret message";
             let key = b"key123";
             
-            let encrypted = xor_encrypt(data, key);
-            let decrypted = xor_decrypt(&encrypted, key);
+            let encrypted = xor_encrypt(data, key).unwrap();
+            let decrypted = xor_decrypt(&encrypted, key).unwrap();
             
             assert_eq!(decrypted, data);
         }
 
+        #[test]
+        fn test_xor_empty_key() {
+            let data = b"secret message";
+            
+            assert_eq!(xor_encrypt(data, b""), Err(Error::CryptoError));
+            assert_eq!(xor_decrypt(data, b""), Err(Error::CryptoError));
+        }
+
         #[test]
         fn test_checksum() {
             let data = b"test data";
@@ -398,13 +832,43 @@ pub mod math_helpers {
         result
     }
 
+    /// `base^exp mod modulus` by square-and-multiply.
+    ///
+    /// Products are taken in `u128`, so no intermediate overflows. Returns 0
+    /// when `modulus` is 1, and also when it is 0, where the result is
+    /// undefined; callers that need to tell these apart should check first.
+    pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+        if modulus <= 1 {
+            return 0;
+        }
+
+        let m = modulus as u128;
+        let mut result = 1u128;
+        let mut b = base as u128 % m;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result * b % m;
+            }
+            b = b * b % m;
+            e >>= 1;
+        }
+
+        result as u64
+    }
+
+    /// Floor of the square root of `n`, exact for every `u64` input.
+    ///
+    /// The first Newton step is written as `n / 2 + n % 2` rather than
+    /// `(n + 1) / 2` so that `u64::MAX` does not overflow.
     pub fn integer_sqrt(n: u64) -> u64 {
         if n < 2 {
             return n;
         }
         
         let mut x = n;
-        let mut y = (x + 1) / 2;
+        let mut y = x / 2 + x % 2;
         
         while y < x {
             x = y;
@@ -428,6 +892,9 @@ pub mod math_helpers {
         log
     }
 
+    /// Greatest common divisor by Euclid's algorithm.
+    ///
+    /// Only uses `%`, so it cannot overflow; `gcd(0, 0)` is 0.
     pub fn gcd(mut a: u64, mut b: u64) -> u64 {
         while b != 0 {
             let temp = b;
@@ -437,11 +904,15 @@ pub mod math_helpers {
         a
     }
 
-    pub fn lcm(a: u64, b: u64) -> u64 {
+    /// Least common multiple, or `None` if it does not fit in a `u64`.
+    ///
+    /// Dividing by the gcd before multiplying keeps the intermediate no
+    /// larger than the result. `lcm(0, n)` is `Some(0)`.
+    pub fn lcm(a: u64, b: u64) -> Option<u64> {
         if a == 0 || b == 0 {
-            return 0;
+            return Some(0);
         }
-        (a / gcd(a, b)).saturating_mul(b)
+        (a / gcd(a, b)).checked_mul(b)
     }
 
     pub fn abs_diff(a: i64, b: i64) -> u64 {
@@ -462,12 +933,40 @@ pub mod math_helpers {
             assert_eq!(fast_pow(5, 3), 125);
         }
 
+        #[test]
+        fn test_mod_pow() {
+            assert_eq!(mod_pow(3, 200, 50), 1);
+            assert_eq!(mod_pow(2, 64, 1_000_000_007), 582_344_008);
+            assert_eq!(
+                mod_pow(12_345_678_901_234_567, 98_765_432_109_876_543, 18_446_744_073_709_551_557),
+                13_902_302_290_042_407_341
+            );
+            assert_eq!(mod_pow(7, 0, 13), 1);
+            assert_eq!(mod_pow(0, 0, 7), 1);
+            assert_eq!(mod_pow(5, 3, 1), 0);
+            assert_eq!(mod_pow(5, 3, 0), 0);
+        }
+
         #[test]
         fn test_integer_sqrt() {
             assert_eq!(integer_sqrt(16), 4);
             assert_eq!(integer_sqrt(100), 10);
         }
 
+        #[test]
+        fn test_integer_sqrt_exact_floor() {
+            assert_eq!(integer_sqrt(0), 0);
+            assert_eq!(integer_sqrt(1), 1);
+            assert_eq!(integer_sqrt(u64::MAX), u32::MAX as u64);
+
+            for root in [2u64, 3, 1000, 65535, 65536, 4294967295] {
+                let square = root * root;
+                assert_eq!(integer_sqrt(square), root);
+                assert_eq!(integer_sqrt(square - 1), root - 1);
+                assert_eq!(integer_sqrt(square + 1), root);
+            }
+        }
+
         #[test]
         fn test_integer_log2() {
             assert_eq!(integer_log2(8), 3);
@@ -481,7 +980,11 @@ pub mod math_helpers {
 
         #[test]
         fn test_lcm() {
-            assert_eq!(lcm(4, 6), 12);
+            assert_eq!(lcm(4, 6), Some(12));
+            assert_eq!(lcm(0, 6), Some(0));
+            assert_eq!(lcm(u64::MAX, 1), Some(u64::MAX));
+            assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+            assert_eq!(lcm(u64::MAX, 2), None);
         }
     }
 }
@@ -559,7 +1062,15 @@ pub mod encoding {
         hex
     }
 
+    pub fn hex_encode_prefixed(bytes: &[u8]) -> String {
+        let mut hex = String::from("0x");
+        hex.push_str(&hex_encode(bytes));
+        hex
+    }
+
     pub fn hex_decode(hex: &str) -> Result<Vec<u8>, &'static str> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        
         if hex.len() % 2 != 0 {
             return Err("Invalid hex length");
         }
@@ -576,6 +1087,38 @@ pub mod encoding {
         Ok(bytes)
     }
 
+    pub fn base58_decode(s: &str) -> Result<Vec<u8>, Error> {
+        const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        
+        let zeros = s.chars().take_while(|&c| c == '1').count();
+        let mut bytes: Vec<u8> = Vec::new();
+        
+        for c in s.chars().skip(zeros) {
+            if !c.is_ascii() {
+                return Err(Error::EncodingError);
+            }
+            let mut carry = ALPHABET.iter()
+                .position(|&a| a == c as u8)
+                .ok_or(Error::EncodingError)? as u32;
+            
+            for byte in bytes.iter_mut() {
+                carry += (*byte as u32) * 58;
+                *byte = (carry & 0xff) as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push((carry & 0xff) as u8);
+                carry >>= 8;
+            }
+        }
+        
+        let mut result = Vec::with_capacity(zeros + bytes.len());
+        result.resize(zeros, 0);
+        result.extend(bytes.iter().rev());
+        
+        Ok(result)
+    }
+
     fn nibble_to_char(nibble: u8) -> char {
         match nibble {
             0..=9 => (b'0' + nibble) as char,
@@ -608,6 +1151,21 @@ pub mod encoding {
             assert_eq!(result, vec![0xde, 0xad, 0xbe, 0xef]);
         }
 
+        #[test]
+        fn test_hex_prefixed_roundtrip() {
+            let encoded = hex_encode_prefixed(&[0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(encoded, "0xdeadbeef");
+            assert_eq!(hex_decode(&encoded).unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(hex_decode("deadbeef").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+        }
+
+        #[test]
+        fn test_base58_decode() {
+            assert_eq!(base58_decode("StV1DL6CwTryKyV").unwrap(), b"hello world");
+            assert_eq!(base58_decode("112").unwrap(), vec![0, 0, 1]);
+            assert_eq!(base58_decode("0OIl"), Err(Error::EncodingError));
+        }
+
         #[test]
         fn test_hex_roundtrip() {
             let original = b"hello world";
@@ -0,0 +1,69 @@
+// Time source abstraction for activity metrics
+//
+// Metrics and managers read the current time through a Clock instead of
+// calling SystemTime directly, so tests can pin time and no_std hosts can
+// supply block time.
+
+// Source of the current Unix time in seconds
+pub trait Clock {
+    fn now_secs(&self) -> u64;
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now_secs(&self) -> u64 {
+        (**self).now_secs()
+    }
+}
+
+// Wall clock backed by SystemTime (std only)
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    // A system clock set before 1970 reads as 0 instead of panicking
+    fn now_secs(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+// Clock pinned to a fixed time, for tests and hosts that provide their own time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_secs(&self) -> u64 {
+        self.0
+    }
+}
+
+// Clock used when a metrics type is named without an explicit clock parameter
+#[cfg(feature = "std")]
+pub type DefaultClock = SystemClock;
+
+#[cfg(not(feature = "std"))]
+pub type DefaultClock = FixedClock;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock(1699430400);
+        assert_eq!(clock.now_secs(), 1699430400);
+
+        let dyn_clock: &dyn Clock = &clock;
+        assert_eq!((&dyn_clock).now_secs(), 1699430400);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_clock() {
+        assert!(SystemClock.now_secs() > 1600000000);
+    }
+}
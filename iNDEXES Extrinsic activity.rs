@@ -1,8 +1,22 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+use crate::math::safe_ratio;
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+
+// Default window for recent activity counts (90 days in seconds)
+pub const RECENT_ACTIVITY_WINDOW_SECS: u64 = 90 * 24 * 60 * 60;
 
 // Extrinsic types
-#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ExtrinsicType {
     Staking,        // Staking related extrinsics
     Governance,     // Governance related extrinsics
@@ -35,6 +49,33 @@ pub struct ExtrinsicRecord {
     pub fee: u128,                 // Transaction fee
 }
 
+// (pallet, call) pairs accepted by add_extrinsic_filtered
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowList {
+    entries: Vec<(String, String)>,
+}
+
+impl AllowList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, pallet: &str, call: &str) -> Self {
+        self.allow(pallet, call);
+        self
+    }
+
+    pub fn allow(&mut self, pallet: &str, call: &str) {
+        if !self.is_allowed(pallet, call) {
+            self.entries.push((pallet.into(), call.into()));
+        }
+    }
+
+    pub fn is_allowed(&self, pallet: &str, call: &str) -> bool {
+        self.entries.iter().any(|(p, c)| p == pallet && c == call)
+    }
+}
+
 // Batch extrinsic record
 #[derive(Debug, Clone)]
 pub struct BatchExtrinsicRecord {
@@ -48,7 +89,7 @@ pub struct BatchExtrinsicRecord {
 
 // Extrinsic activity metrics
 #[derive(Debug, Clone)]
-pub struct ExtrinsicActivityMetrics {
+pub struct ExtrinsicActivityMetrics<C = DefaultClock> {
     pub account_id: u32,                           // Account ID
     pub extrinsics: Vec<ExtrinsicRecord>,          // Extrinsic records
     pub batch_extrinsics: Vec<BatchExtrinsicRecord>, // Batch extrinsic records
@@ -59,14 +100,20 @@ pub struct ExtrinsicActivityMetrics {
     pub failed_extrinsics: u32,                     // Failed extrinsics count
     pub total_fees_paid: u128,                      // Total fees paid
     pub last_activity_time: u64,                    // Last activity timestamp
+    pub clock: C,                                   // Time source
 }
 
-impl ExtrinsicActivityMetrics {
+#[cfg(feature = "std")]
+impl ExtrinsicActivityMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> ExtrinsicActivityMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         ExtrinsicActivityMetrics {
             account_id,
@@ -79,6 +126,7 @@ impl ExtrinsicActivityMetrics {
             failed_extrinsics: 0,
             total_fees_paid: 0,
             last_activity_time: now,
+            clock,
         }
     }
 
@@ -90,10 +138,7 @@ impl ExtrinsicActivityMetrics {
     // Add extrinsic
     pub fn add_extrinsic(&mut self, pallet: String, call: String, extrinsic_type: ExtrinsicType, 
                         block_number: u32, success: bool, weight: u64, fee: u128) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let extrinsic_id = self.extrinsics.len() as u32 + 1;
         
@@ -134,6 +179,20 @@ impl ExtrinsicActivityMetrics {
         self.last_activity_time = timestamp;
     }
 
+    // Records the extrinsic only if allow_list is None or contains its
+    // (pallet, call) pair; returns whether it was recorded
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_extrinsic_filtered(&mut self, allow_list: Option<&AllowList>, pallet: String, call: String,
+                                  extrinsic_type: ExtrinsicType, block_number: u32, success: bool,
+                                  weight: u64, fee: u128) -> bool {
+        if allow_list.is_some_and(|list| !list.is_allowed(&pallet, &call)) {
+            return false;
+        }
+
+        self.add_extrinsic(pallet, call, extrinsic_type, block_number, success, weight, fee);
+        true
+    }
+
     // Get extrinsic records
     pub fn get_extrinsics(&self) -> &Vec<ExtrinsicRecord> {
         &self.extrinsics
@@ -149,6 +208,15 @@ impl ExtrinsicActivityMetrics {
         &self.extrinsic_types
     }
 
+    // Extrinsic type counts ordered by type, for stable output
+    pub fn get_extrinsic_types_sorted(&self) -> Vec<(ExtrinsicType, u32)> {
+        let mut types: Vec<(ExtrinsicType, u32)> = self.extrinsic_types.iter()
+            .map(|(extrinsic_type, count)| (extrinsic_type.clone(), *count))
+            .collect();
+        types.sort();
+        types
+    }
+
     // 3. Extrinsic timing (how regular, how recent)
     pub fn get_extrinsic_timing(&self) -> Option<(u64, u64)> {
         if let (Some(first), Some(last)) = (self.first_extrinsic_date, self.last_extrinsic_date) {
@@ -161,11 +229,8 @@ impl ExtrinsicActivityMetrics {
     // Check if activity is regular (active within last 30 days)
     pub fn is_activity_regular(&self) -> bool {
         if let Some(last) = self.last_extrinsic_date {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards")
-                .as_secs();
-            let days_since_last = (now - last) / (24 * 60 * 60);
+            let now = self.clock.now_secs();
+            let days_since_last = now.saturating_sub(last) / (24 * 60 * 60);
             days_since_last <= 30
         } else {
             false
@@ -206,63 +271,43 @@ impl ExtrinsicActivityMetrics {
 
     // 7. Governance related extrinsic ratio
     pub fn get_governance_extrinsic_ratio(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
         let governance_count = self.extrinsic_types.get(&ExtrinsicType::Governance)
             .copied()
             .unwrap_or(0);
             
-        governance_count as f64 / self.extrinsics.len() as f64
+        safe_ratio(governance_count as f64, self.extrinsics.len() as f64)
     }
 
     // 8. Staking related extrinsic ratio
     pub fn get_staking_extrinsic_ratio(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
         let staking_count = self.extrinsic_types.get(&ExtrinsicType::Staking)
             .copied()
             .unwrap_or(0);
             
-        staking_count as f64 / self.extrinsics.len() as f64
+        safe_ratio(staking_count as f64, self.extrinsics.len() as f64)
     }
 
     // 9. Identity related extrinsic ratio
     pub fn get_identity_extrinsic_ratio(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
         let identity_count = self.extrinsic_types.get(&ExtrinsicType::Identity)
             .copied()
             .unwrap_or(0);
             
-        identity_count as f64 / self.extrinsics.len() as f64
+        safe_ratio(identity_count as f64, self.extrinsics.len() as f64)
     }
 
     // 10. Transfer operations ratio
     pub fn get_transfer_extrinsic_ratio(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
         let transfer_count = self.extrinsic_types.get(&ExtrinsicType::Transfer)
             .copied()
             .unwrap_or(0);
             
-        transfer_count as f64 / self.extrinsics.len() as f64
+        safe_ratio(transfer_count as f64, self.extrinsics.len() as f64)
     }
 
     // Get success rate
     pub fn get_success_rate(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
-        self.successful_extrinsics as f64 / self.extrinsics.len() as f64
+        safe_ratio(self.successful_extrinsics as f64, self.extrinsics.len() as f64)
     }
 
     // Get total fees paid
@@ -270,12 +315,47 @@ impl ExtrinsicActivityMetrics {
         self.total_fees_paid
     }
 
+    // Mean fee per recorded extrinsic, 0.0 with no extrinsics
+    pub fn average_fee(&self) -> f64 {
+        let total: f64 = self.extrinsics.iter().map(|e| e.fee as f64).sum();
+        safe_ratio(total, self.extrinsics.len() as f64)
+    }
+
+    // Highest single extrinsic fee, 0 with no extrinsics
+    pub fn max_fee(&self) -> u128 {
+        self.extrinsics.iter().map(|e| e.fee).max().unwrap_or(0)
+    }
+
+    // Extrinsics whose fee exceeds mean + k * std (population std of all
+    // recorded fees). Empty when there are no extrinsics or all fees are equal.
+    pub fn fee_outliers(&self, k: f64) -> Vec<&ExtrinsicRecord> {
+        let mean = self.average_fee();
+        let variance = safe_ratio(
+            self.extrinsics.iter().map(|e| (e.fee as f64 - mean) * (e.fee as f64 - mean)).sum(),
+            self.extrinsics.len() as f64,
+        );
+        if variance <= 0.0 || k.is_nan() {
+            return Vec::new();
+        }
+
+        // Compares squared distances to avoid sqrt, which core lacks without std:
+        // fee - mean > k * std
+        let threshold = k * k * variance;
+        self.extrinsics.iter()
+            .filter(|e| {
+                let excess = e.fee as f64 - mean;
+                if k >= 0.0 {
+                    excess > 0.0 && excess * excess > threshold
+                } else {
+                    excess >= 0.0 || excess * excess < threshold
+                }
+            })
+            .collect()
+    }
+
     // 11. Batch extrinsic usage (bulk transaction submission)
     pub fn add_batch_extrinsic(&mut self, extrinsics: Vec<ExtrinsicRecord>, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let batch_id = self.batch_extrinsics.len() as u32 + 1;
         let total_weight: u64 = extrinsics.iter().map(|e| e.weight).sum();
@@ -290,9 +370,6 @@ impl ExtrinsicActivityMetrics {
             total_fee,
         };
         
-        self.batch_extrinsics.push(batch);
-        self.extrinsics.extend(extrinsics);
-        
         // Update counts and fees
         for extrinsic in &batch.extrinsics {
             if extrinsic.success {
@@ -307,6 +384,9 @@ impl ExtrinsicActivityMetrics {
             *count += 1;
         }
         
+        self.batch_extrinsics.push(batch);
+        self.extrinsics.extend(extrinsics);
+        
         // Update first and last extrinsic dates
         if self.first_extrinsic_date.is_none() {
             self.first_extrinsic_date = Some(timestamp);
@@ -328,15 +408,11 @@ impl ExtrinsicActivityMetrics {
 
     // Get batch usage ratio
     pub fn get_batch_usage_ratio(&self) -> f64 {
-        if self.extrinsics.is_empty() {
-            return 0.0;
-        }
-        
         let batch_extrinsic_count: usize = self.batch_extrinsics.iter()
             .map(|b| b.extrinsics.len())
             .sum();
             
-        batch_extrinsic_count as f64 / self.extrinsics.len() as f64
+        safe_ratio(batch_extrinsic_count as f64, self.extrinsics.len() as f64)
     }
 
     // Get activity score based on various factors
@@ -372,14 +448,15 @@ impl ExtrinsicActivityMetrics {
 
     // Get recent activity count (last 90 days)
     pub fn get_recent_activity_count(&self) -> u32 {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        let ninety_days_ago = now - (90 * 24 * 60 * 60);
+        self.get_recent_activity_count_within(RECENT_ACTIVITY_WINDOW_SECS)
+    }
+
+    // Get count of activities within the given window (seconds) before now
+    pub fn get_recent_activity_count_within(&self, window_secs: u64) -> u32 {
+        let cutoff = self.clock.now_secs().saturating_sub(window_secs);
         
         self.extrinsics.iter()
-            .filter(|e| e.timestamp > ninety_days_ago)
+            .filter(|e| e.timestamp > cutoff)
             .count() as u32
     }
 
@@ -390,35 +467,81 @@ impl ExtrinsicActivityMetrics {
 }
 
 // Extrinsic activity manager
-pub struct ExtrinsicActivityManager {
-    pub metrics: HashMap<u32, ExtrinsicActivityMetrics>, // Account ID -> Metrics
+pub struct ExtrinsicActivityManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, ExtrinsicActivityMetrics<C>>, // Account ID -> Metrics
+    pub clock: C,                                           // Time source for new metrics
 }
 
-impl ExtrinsicActivityManager {
+#[cfg(feature = "std")]
+impl ExtrinsicActivityManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> ExtrinsicActivityManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         ExtrinsicActivityManager {
             metrics: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &ExtrinsicActivityMetrics {
-        let metrics = ExtrinsicActivityMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &ExtrinsicActivityMetrics<C> {
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| ExtrinsicActivityMetrics::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&ExtrinsicActivityMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&ExtrinsicActivityMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, ExtrinsicActivityMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, ExtrinsicActivityMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: ExtrinsicActivityMetrics<C>) -> Option<ExtrinsicActivityMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<ExtrinsicActivityMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut manager = ExtrinsicActivityManager::with_clock(FixedClock(1699430400));
+        manager.create_metrics(1);
+        
+        let metrics = manager.metrics.get_mut(&1).unwrap();
+        metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer, 
+                              100, true, 1000, 10);
+        assert_eq!(metrics.get_extrinsics()[0].timestamp, 1699430400);
+        assert_eq!(metrics.get_last_activity_time(), 1699430400);
+    }
+
+    #[test]
+    fn test_clock_moved_backwards() {
+        let mut metrics = ExtrinsicActivityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer, 
+                              100, true, 1000, 10);
+        
+        metrics.clock = FixedClock(1699430400 - 3600);
+        assert!(metrics.is_activity_regular());
+    }
+
+    crate::manager_tests!(ExtrinsicActivityManager, ExtrinsicActivityMetrics, total_fees_paid);
 
     #[test]
     fn test_extrinsic_metrics_creation() {
@@ -479,6 +602,13 @@ mod tests {
         assert_eq!(*types.get(&ExtrinsicType::Staking).unwrap(), 1);
         assert_eq!(*types.get(&ExtrinsicType::Governance).unwrap(), 1);
         assert_eq!(*types.get(&ExtrinsicType::Identity).unwrap(), 1);
+
+        assert_eq!(metrics.get_extrinsic_types_sorted(), vec![
+            (ExtrinsicType::Staking, 1),
+            (ExtrinsicType::Governance, 1),
+            (ExtrinsicType::Identity, 1),
+            (ExtrinsicType::Transfer, 1),
+        ]);
     }
 
     #[test]
@@ -562,6 +692,69 @@ mod tests {
         assert_eq!(metrics.get_success_rate(), 2.0 / 3.0);
     }
 
+    #[test]
+    fn test_fee_statistics() {
+        let mut metrics = ExtrinsicActivityMetrics::with_clock(1, FixedClock(1699430400));
+        assert_eq!(metrics.average_fee(), 0.0);
+        assert_eq!(metrics.max_fee(), 0);
+        assert!(metrics.fee_outliers(2.0).is_empty());
+
+        for block in 0..9 {
+            metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer,
+                                  block, true, 1000, 100);
+        }
+        // Identical fees have no spread, so nothing stands out
+        assert_eq!(metrics.average_fee(), 100.0);
+        assert!(metrics.fee_outliers(0.0).is_empty());
+
+        metrics.add_extrinsic("utility".to_string(), "batch_all".to_string(), ExtrinsicType::Utility,
+                              9, true, 5000, 10_000);
+        assert_eq!(metrics.average_fee(), 1090.0);
+        assert_eq!(metrics.max_fee(), 10_000);
+
+        // std is 2970, so the 10_000 fee sits 3 std above the mean
+        let outliers = metrics.fee_outliers(2.0);
+        assert_eq!(outliers.len(), 1);
+        assert_eq!(outliers[0].fee, 10_000);
+        assert_eq!(outliers[0].call, "batch_all");
+        assert!(metrics.fee_outliers(3.5).is_empty());
+
+        // A negative k lowers the bar below the mean
+        assert_eq!(metrics.fee_outliers(-1.0).len(), 10);
+    }
+
+    #[test]
+    fn test_add_extrinsic_filtered() {
+        let mut metrics = ExtrinsicActivityMetrics::with_clock(1, FixedClock(1699430400));
+        let allow_list = AllowList::new()
+            .with("staking", "bond")
+            .with("staking", "nominate")
+            .with("democracy", "vote");
+
+        assert!(metrics.add_extrinsic_filtered(Some(&allow_list), "staking".to_string(), "bond".to_string(),
+                                               ExtrinsicType::Staking, 1, true, 1000, 100));
+        assert!(metrics.add_extrinsic_filtered(Some(&allow_list), "democracy".to_string(), "vote".to_string(),
+                                               ExtrinsicType::Democracy, 2, true, 1000, 100));
+        // The balances pallet is not on the list, so dust transfers are skipped
+        for block in 3..8 {
+            assert!(!metrics.add_extrinsic_filtered(Some(&allow_list), "balances".to_string(),
+                                                    "transfer".to_string(), ExtrinsicType::Transfer,
+                                                    block, true, 1000, 1));
+        }
+        // Only listed calls of a listed pallet are accepted
+        assert!(!metrics.add_extrinsic_filtered(Some(&allow_list), "staking".to_string(), "chill".to_string(),
+                                                ExtrinsicType::Staking, 8, true, 1000, 100));
+
+        assert_eq!(metrics.get_total_extrinsic_count(), 2);
+        assert_eq!(metrics.get_total_fees_paid(), 200);
+        assert_eq!(metrics.get_extrinsic_types().get(&ExtrinsicType::Transfer), None);
+
+        // Without a list everything is recorded
+        assert!(metrics.add_extrinsic_filtered(None, "balances".to_string(), "transfer".to_string(),
+                                               ExtrinsicType::Transfer, 9, true, 1000, 1));
+        assert_eq!(metrics.get_total_extrinsic_count(), 3);
+    }
+
     #[test]
     fn test_batch_extrinsic_usage() {
         let mut manager = ExtrinsicActivityManager::new();
@@ -625,4 +818,20 @@ mod tests {
         let recent_count = metrics.get_recent_activity_count();
         assert!(recent_count >= 0);
     }
+
+    #[test]
+    fn test_recent_activity_window() {
+        let now = 1699430400;
+        let mut metrics = ExtrinsicActivityMetrics::with_clock(1, FixedClock(now - 200 * 24 * 60 * 60));
+        metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer,
+                              100, true, 1000, 10);
+        metrics.clock = FixedClock(now - 20 * 24 * 60 * 60);
+        metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer,
+                              200, true, 1000, 10);
+        metrics.clock = FixedClock(now);
+
+        assert_eq!(metrics.get_recent_activity_count(), 1);
+        assert_eq!(metrics.get_recent_activity_count_within(7 * 24 * 60 * 60), 0);
+        assert_eq!(metrics.get_recent_activity_count_within(365 * 24 * 60 * 60), 2);
+    }
 }
@@ -0,0 +1,50 @@
+// Shared tests for the per-account metrics managers
+//
+// Every activity manager exposes the same map-style API over its metrics, so
+// the common behaviour is tested once here and instantiated in each module's
+// tests with crate::manager_tests!.
+#![cfg(test)]
+
+// Expands to the shared manager tests.
+// $manager: manager type with new/create_metrics/insert_metrics/remove_metrics
+// $metrics: metrics type stored by the manager, with new(account_id)
+// $counter: numeric metrics field used to tell existing metrics from fresh ones
+//
+// Managers that name their map and methods after what they store pass those
+// names explicitly: map field, then create/get/insert/remove methods.
+#[macro_export]
+macro_rules! manager_tests {
+    ($manager:ident, $metrics:ident, $counter:ident) => {
+        $crate::manager_tests!($manager, $metrics, $counter,
+                               metrics, create_metrics, get_metrics, insert_metrics, remove_metrics);
+    };
+    ($manager:ident, $metrics:ident, $counter:ident,
+     $map:ident, $create:ident, $get:ident, $insert:ident, $remove:ident) => {
+        #[test]
+        fn test_create_metrics_keeps_existing() {
+            let mut manager = $manager::new();
+            manager.$create(1);
+            manager.$map.get_mut(&1).unwrap().$counter = 3;
+
+            let metrics = manager.$create(1);
+            assert_eq!(metrics.$counter, 3);
+            assert_eq!(manager.$map.len(), 1);
+        }
+
+        #[test]
+        fn test_manager_insert_and_remove() {
+            let mut manager = $manager::new();
+            manager.$create(1);
+
+            let removed = manager.$remove(1);
+            assert_eq!(removed.map(|m| m.account_id), Some(1));
+            assert!(manager.$get(1).is_none());
+            assert!(manager.$remove(1).is_none());
+
+            assert!(manager.$insert(2, $metrics::new(2)).is_none());
+            let previous = manager.$insert(2, $metrics::new(2));
+            assert_eq!(previous.map(|m| m.account_id), Some(2));
+            assert_eq!(manager.$map.len(), 1);
+        }
+    };
+}
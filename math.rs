@@ -0,0 +1,31 @@
+// Numeric helpers shared by activity metrics
+
+// numerator / denominator, or 0.0 when the denominator is 0 (or the
+// division is otherwise undefined, e.g. NaN inputs). Ratios and rates across
+// the metrics modules use this so an account with no activity reads as 0.0
+// instead of NaN or infinity.
+pub fn safe_ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        return 0.0;
+    }
+
+    let ratio = numerator / denominator;
+    if ratio.is_nan() { 0.0 } else { ratio }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_ratio() {
+        assert_eq!(safe_ratio(3.0, 4.0), 0.75);
+        assert_eq!(safe_ratio(-1.0, 2.0), -0.5);
+        assert_eq!(safe_ratio(0.0, 5.0), 0.0);
+
+        assert_eq!(safe_ratio(5.0, 0.0), 0.0);
+        assert_eq!(safe_ratio(0.0, 0.0), 0.0);
+        assert_eq!(safe_ratio(5.0, -0.0), 0.0);
+        assert_eq!(safe_ratio(f64::NAN, 2.0), 0.0);
+    }
+}
@@ -1,5 +1,18 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+
+// Window counted by the trust score's recency factor (90 days in seconds)
+pub const RECENT_ACTIVITY_WINDOW_SECS: u64 = 90 * 24 * 60 * 60;
 
 // Off-chain participation types
 #[derive(Debug, Clone, PartialEq)]
@@ -63,6 +76,30 @@ pub struct CommunityRole {
     pub timestamp: u64,            // Role timestamp
 }
 
+// Approval status of a third-party reference
+// Migration: records stored with the old String field can be converted with
+// `"Approved".parse::<ApprovalStatus>()`. Parsing is case-insensitive and
+// unknown values return an error; callers should treat those as Pending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalStatus {
+    Approved,   // Reference confirmed by the source
+    Pending,    // Awaiting confirmation
+    Rejected,   // Reference declined
+}
+
+impl core::str::FromStr for ApprovalStatus {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "approved" => Ok(ApprovalStatus::Approved),
+            "pending" => Ok(ApprovalStatus::Pending),
+            "rejected" => Ok(ApprovalStatus::Rejected),
+            _ => Err("Unknown approval status"),
+        }
+    }
+}
+
 // Third-party references and approvals record
 #[derive(Debug, Clone)]
 pub struct ThirdPartyReference {
@@ -70,7 +107,7 @@ pub struct ThirdPartyReference {
     pub account_id: u32,           // Account ID
     pub reference_type: String,    // Grant, Partnership, Project
     pub source: String,            // Reference source
-    pub approval_status: String,   // Approved, Pending, Rejected
+    pub approval_status: ApprovalStatus, // Approved, Pending, Rejected
     pub credibility_score: f64,    // Credibility score (0-1)
     pub timestamp: u64,            // Reference timestamp
 }
@@ -99,9 +136,49 @@ pub struct LocalCommunityInteraction {
     pub timestamp: u64,            // Interaction timestamp
 }
 
+// Per-channel multipliers for the off-chain trust score
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffChainWeights {
+    pub polkassembly: f64,           // Per Polkassembly activity
+    pub github: f64,                 // Per PR/issue/review
+    pub social_media: f64,           // Per content item
+    pub community_roles: f64,        // Per community role
+    pub third_party_references: f64, // Per approved reference
+    pub community_voting: f64,       // Per poll participation
+    pub local_interactions: f64,     // Per local interaction
+    pub engagement: f64,             // Multiplier for average engagement
+    pub credibility: f64,            // Multiplier for average credibility
+    pub impact: f64,                 // Multiplier for average local impact
+    pub recency: f64,                // Per activity in the last 90 days
+    pub engagement_half_life_days: f64, // Age at which content engagement counts half (<= 0 disables decay)
+    pub language_diversity: f64,     // Per distinct language beyond the first
+    pub max_extra_languages: u32,    // Cap on languages counted for the diversity bonus
+}
+
+impl Default for OffChainWeights {
+    fn default() -> Self {
+        OffChainWeights {
+            polkassembly: 1.0,
+            github: 0.5,
+            social_media: 1.5,
+            community_roles: 2.0,
+            third_party_references: 3.0,
+            community_voting: 0.5,
+            local_interactions: 1.0,
+            engagement: 10.0,
+            credibility: 15.0,
+            impact: 10.0,
+            recency: 0.2,
+            engagement_half_life_days: 180.0,
+            language_diversity: 1.0,
+            max_extra_languages: 4,
+        }
+    }
+}
+
 // Off-chain social trust metrics
 #[derive(Debug, Clone)]
-pub struct OffChainSocialTrustMetrics {
+pub struct OffChainSocialTrustMetrics<C = DefaultClock> {
     pub account_id: u32,                           // Account ID
     pub total_offchain_activities: u32,            // Total off-chain activities
     pub polkassembly_activities: Vec<PolkassemblyActivity>, // Polkassembly activities
@@ -112,15 +189,22 @@ pub struct OffChainSocialTrustMetrics {
     pub community_voting: Vec<CommunityVoting>,             // Community voting
     pub local_interactions: Vec<LocalCommunityInteraction>, // Local interactions
     pub offchain_trust_score: f64,                          // Overall off-chain trust score
+    pub weights: OffChainWeights,                           // Weights used for the trust score
     pub last_updated: u64,                                  // Last update timestamp
+    pub clock: C,                                           // Time source
 }
 
-impl OffChainSocialTrustMetrics {
+#[cfg(feature = "std")]
+impl OffChainSocialTrustMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> OffChainSocialTrustMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         OffChainSocialTrustMetrics {
             account_id,
@@ -133,17 +217,16 @@ impl OffChainSocialTrustMetrics {
             community_voting: Vec::new(),
             local_interactions: Vec::new(),
             offchain_trust_score: 0.0,
+            weights: OffChainWeights::default(),
             last_updated: now,
+            clock,
         }
     }
 
     // Polkassembly discussion and comment interactions
     pub fn add_polkassembly_activity(&mut self, post_id: u32, account_id: u32, activity_type: String,
                                    content_length: u32, upvotes: u32, replies: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = PolkassemblyActivity {
             post_id,
@@ -165,12 +248,23 @@ impl OffChainSocialTrustMetrics {
     }
 
     // GitHub contributions (PR count, issue participation, repo diversity)
+    // Contributions are keyed by repo_name: re-adding a repo treats the counts as
+    // cumulative totals and keeps the maximum of old and new, so re-ingesting the
+    // same data does not double count.
     pub fn add_github_contribution(&mut self, repo_name: String, account_id: u32, contribution_type: String,
                                  pr_count: u32, issue_count: u32, review_count: u32, repo_diversity: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
+        
+        if let Some(existing) = self.github_contributions.iter_mut().find(|c| c.repo_name == repo_name) {
+            existing.contribution_type = contribution_type;
+            existing.pr_count = existing.pr_count.max(pr_count);
+            existing.issue_count = existing.issue_count.max(issue_count);
+            existing.review_count = existing.review_count.max(review_count);
+            existing.repo_diversity = existing.repo_diversity.max(repo_diversity);
+            existing.timestamp = timestamp;
+            self.update_offchain_trust_score();
+            return;
+        }
             
         let contribution = GitHubContribution {
             repo_name,
@@ -192,13 +286,18 @@ impl OffChainSocialTrustMetrics {
         &self.github_contributions
     }
 
+    // Number of distinct repositories contributed to
+    pub fn distinct_repos(&self) -> usize {
+        let mut repos: Vec<&str> = self.github_contributions.iter().map(|c| c.repo_name.as_str()).collect();
+        repos.sort();
+        repos.dedup();
+        repos.len()
+    }
+
     // Social media / educational content (tweet/thread count, blog posts, videos)
     pub fn add_social_media_content(&mut self, content_id: u32, account_id: u32, platform: String,
                                   content_type: String, engagement_score: f64, reach: u32, shares: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let content = SocialMediaContent {
             content_id,
@@ -220,13 +319,30 @@ impl OffChainSocialTrustMetrics {
         &self.social_media_content
     }
 
+    // Average engagement with each item decayed by age (half-life from weights)
+    pub fn recent_engagement(&self, now: u64) -> f64 {
+        if self.social_media_content.is_empty() {
+            return 0.0;
+        }
+        
+        let half_life_secs = self.weights.engagement_half_life_days * 24.0 * 60.0 * 60.0;
+        let total: f64 = self.social_media_content.iter()
+            .map(|c| {
+                if half_life_secs <= 0.0 {
+                    return c.engagement_score;
+                }
+                let age = now.saturating_sub(c.timestamp) as f64;
+                c.engagement_score * powf(0.5, age / half_life_secs)
+            })
+            .sum();
+        
+        total / self.social_media_content.len() as f64
+    }
+
     // Community roles and event participation (moderator, DAO tasks, event speaking)
     pub fn add_community_role(&mut self, role_id: u32, account_id: u32, role_type: String,
                            event_name: String, participation_type: String, duration: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let role = CommunityRole {
             role_id,
@@ -249,11 +365,8 @@ impl OffChainSocialTrustMetrics {
 
     // References and third-party approvals (grant references, joint projects)
     pub fn add_third_party_reference(&mut self, reference_id: u32, account_id: u32, reference_type: String,
-                                   source: String, approval_status: String, credibility_score: f64) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+                                   source: String, approval_status: ApprovalStatus, credibility_score: f64) {
+        let timestamp = self.clock.now_secs();
             
         let reference = ThirdPartyReference {
             reference_id,
@@ -274,13 +387,17 @@ impl OffChainSocialTrustMetrics {
         &self.third_party_references
     }
 
+    pub fn references_by_status(&self, status: ApprovalStatus) -> Vec<&ThirdPartyReference> {
+        self.third_party_references
+            .iter()
+            .filter(|r| r.approval_status == status)
+            .collect()
+    }
+
     // Community polls and off-chain signatures
     pub fn add_community_voting(&mut self, poll_id: u32, account_id: u32, poll_type: String,
                               participation: bool, vote_choice: Option<String>, signature_count: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let voting = CommunityVoting {
             poll_id,
@@ -304,10 +421,7 @@ impl OffChainSocialTrustMetrics {
     // Local language community interaction metrics
     pub fn add_local_community_interaction(&mut self, interaction_id: u32, account_id: u32, language: String,
                                          interaction_type: String, community_size: u32, impact_score: f64) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let interaction = LocalCommunityInteraction {
             interaction_id,
@@ -328,50 +442,64 @@ impl OffChainSocialTrustMetrics {
         &self.local_interactions
     }
 
+    // Number of distinct language codes (trimmed, case-insensitive)
+    pub fn distinct_languages(&self) -> usize {
+        let mut languages: Vec<String> = self.local_interactions.iter()
+            .map(|i| i.language.trim().to_lowercase())
+            .filter(|l| !l.is_empty())
+            .collect();
+        languages.sort();
+        languages.dedup();
+        languages.len()
+    }
+
+    // Re-derive the trust score from stored records using new weights
+    pub fn recompute_score(&mut self, weights: &OffChainWeights) {
+        self.weights = *weights;
+        self.update_offchain_trust_score();
+    }
+
     // Update off-chain trust score based on participation metrics
     fn update_offchain_trust_score(&mut self) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let now = self.clock.now_secs();
+        let weights = self.weights;
             
         // Base score calculation based on various factors
         let mut score = 0.0;
         
         // Positive contributions
-        score += self.polkassembly_activities.len() as f64 * 1.0;
-        score += self.github_contributions.iter().map(|c| c.pr_count + c.issue_count + c.review_count).sum::<u32>() as f64 * 0.5;
-        score += self.social_media_content.len() as f64 * 1.5;
-        score += self.community_roles.len() as f64 * 2.0;
-        score += self.third_party_references.iter().filter(|r| r.approval_status == "Approved").count() as f64 * 3.0;
-        score += self.community_voting.iter().filter(|v| v.participation).count() as f64 * 0.5;
-        score += self.local_interactions.len() as f64 * 1.0;
+        score += self.polkassembly_activities.len() as f64 * weights.polkassembly;
+        score += self.github_contributions.iter().map(|c| c.pr_count + c.issue_count + c.review_count).sum::<u32>() as f64 * weights.github;
+        score += self.social_media_content.len() as f64 * weights.social_media;
+        score += self.community_roles.len() as f64 * weights.community_roles;
+        score += self.references_by_status(ApprovalStatus::Approved).len() as f64 * weights.third_party_references;
+        score += self.community_voting.iter().filter(|v| v.participation).count() as f64 * weights.community_voting;
+        score += self.local_interactions.len() as f64 * weights.local_interactions;
         
         // Quality factors
-        let avg_engagement: f64 = if !self.social_media_content.is_empty() {
-            self.social_media_content.iter().map(|c| c.engagement_score).sum::<f64>() / self.social_media_content.len() as f64
-        } else {
-            0.0
-        };
-        score += avg_engagement * 10.0;
+        score += self.recent_engagement(now) * weights.engagement;
         
         let avg_credibility: f64 = if !self.third_party_references.is_empty() {
             self.third_party_references.iter().map(|r| r.credibility_score).sum::<f64>() / self.third_party_references.len() as f64
         } else {
             0.0
         };
-        score += avg_credibility * 15.0;
+        score += avg_credibility * weights.credibility;
         
         let avg_impact: f64 = if !self.local_interactions.is_empty() {
             self.local_interactions.iter().map(|i| i.impact_score).sum::<f64>() / self.local_interactions.len() as f64
         } else {
             0.0
         };
-        score += avg_impact * 10.0;
+        score += avg_impact * weights.impact;
+        
+        // Bonus for bridging multiple language communities
+        let extra_languages = (self.distinct_languages().saturating_sub(1) as u32).min(weights.max_extra_languages);
+        score += extra_languages as f64 * weights.language_diversity;
         
         // Recency factor - more recent activities get higher weight
-        let recent_activities = self.get_recent_offchain_activities_count(now);
-        score += recent_activities as f64 * 0.2;
+        let recent_activities = self.get_recent_offchain_activities_count();
+        score += recent_activities as f64 * weights.recency;
         
         // Normalize score to 0-100 range
         self.offchain_trust_score = score.max(0.0).min(100.0);
@@ -379,24 +507,29 @@ impl OffChainSocialTrustMetrics {
     }
 
     // Get count of recent off-chain activities (within last 90 days)
-    fn get_recent_offchain_activities_count(&self, current_time: u64) -> u32 {
-        let ninety_days_ago = current_time - (90 * 24 * 60 * 60); // 90 days in seconds
+    pub fn get_recent_offchain_activities_count(&self) -> u32 {
+        self.get_recent_offchain_activities_count_within(RECENT_ACTIVITY_WINDOW_SECS)
+    }
+
+    // Get count of off-chain activities within the given window (seconds) before now
+    pub fn get_recent_offchain_activities_count_within(&self, window_secs: u64) -> u32 {
+        let cutoff = self.clock.now_secs().saturating_sub(window_secs);
         let mut count = 0;
         
         count += self.polkassembly_activities.iter()
-            .filter(|a| a.timestamp > ninety_days_ago).count();
+            .filter(|a| a.timestamp > cutoff).count();
         count += self.github_contributions.iter()
-            .filter(|c| c.timestamp > ninety_days_ago).count();
+            .filter(|c| c.timestamp > cutoff).count();
         count += self.social_media_content.iter()
-            .filter(|c| c.timestamp > ninety_days_ago).count();
+            .filter(|c| c.timestamp > cutoff).count();
         count += self.community_roles.iter()
-            .filter(|r| r.timestamp > ninety_days_ago).count();
+            .filter(|r| r.timestamp > cutoff).count();
         count += self.third_party_references.iter()
-            .filter(|r| r.timestamp > ninety_days_ago).count();
+            .filter(|r| r.timestamp > cutoff).count();
         count += self.community_voting.iter()
-            .filter(|v| v.timestamp > ninety_days_ago).count();
+            .filter(|v| v.timestamp > cutoff).count();
         count += self.local_interactions.iter()
-            .filter(|i| i.timestamp > ninety_days_ago).count();
+            .filter(|i| i.timestamp > cutoff).count();
             
         count as u32
     }
@@ -412,36 +545,105 @@ impl OffChainSocialTrustMetrics {
     }
 }
 
+// Float power; core has no float math, so no_std builds use libm
+#[cfg(feature = "std")]
+fn powf(base: f64, exp: f64) -> f64 {
+    base.powf(exp)
+}
+
+#[cfg(not(feature = "std"))]
+fn powf(base: f64, exp: f64) -> f64 {
+    libm::pow(base, exp)
+}
+
 // Off-chain social trust metrics manager
-pub struct OffChainTrustManager {
-    pub metrics: HashMap<u32, OffChainSocialTrustMetrics>, // Account ID -> Metrics
+pub struct OffChainTrustManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, OffChainSocialTrustMetrics<C>>, // Account ID -> Metrics
+    pub weights: OffChainWeights,                          // Weights applied to new metrics
+    pub clock: C,                                          // Time source for new metrics
 }
 
-impl OffChainTrustManager {
+#[cfg(feature = "std")]
+impl OffChainTrustManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_weights(OffChainWeights::default())
+    }
+
+    pub fn with_weights(weights: OffChainWeights) -> Self {
         OffChainTrustManager {
             metrics: HashMap::new(),
+            weights,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<C: Clock + Clone> OffChainTrustManager<C> {
+    // Manager with default weights whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
+        OffChainTrustManager {
+            metrics: HashMap::new(),
+            weights: OffChainWeights::default(),
+            clock,
+        }
+    }
+
+    // Change weights and recompute every account's score
+    pub fn set_weights(&mut self, weights: OffChainWeights) {
+        self.weights = weights;
+        for metrics in self.metrics.values_mut() {
+            metrics.recompute_score(&weights);
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &OffChainSocialTrustMetrics {
-        let metrics = OffChainSocialTrustMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &OffChainSocialTrustMetrics<C> {
+        let weights = self.weights;
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| {
+                let mut metrics = OffChainSocialTrustMetrics::with_clock(account_id, self.clock.clone());
+                metrics.weights = weights;
+                metrics
+            })
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&OffChainSocialTrustMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&OffChainSocialTrustMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, OffChainSocialTrustMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, OffChainSocialTrustMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: OffChainSocialTrustMetrics<C>) -> Option<OffChainSocialTrustMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<OffChainSocialTrustMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut manager = OffChainTrustManager::with_clock(FixedClock(1699430400));
+        manager.create_metrics(1);
+        
+        let metrics = manager.metrics.get_mut(&1).unwrap();
+        metrics.add_polkassembly_activity(1, 1, "Post".to_string(), 500, 10, 2);
+        assert_eq!(metrics.get_polkassembly_activities()[0].timestamp, 1699430400);
+        assert_eq!(metrics.last_updated, 1699430400);
+    }
+
+    crate::manager_tests!(OffChainTrustManager, OffChainSocialTrustMetrics, total_offchain_activities);
 
     #[test]
     fn test_offchain_trust_metrics_creation() {
@@ -486,6 +688,28 @@ mod tests {
         assert_eq!(metrics.get_total_offchain_activities(), 1);
     }
 
+    #[test]
+    fn test_github_contribution_dedup() {
+        let mut metrics = OffChainSocialTrustMetrics::new(1);
+        metrics.add_github_contribution("polkadot-sdk".to_string(), 1, "PR".to_string(), 5, 2, 1, 1);
+        let score = metrics.get_offchain_trust_score();
+        
+        // Re-ingesting the same repo does not double count
+        metrics.add_github_contribution("polkadot-sdk".to_string(), 1, "PR".to_string(), 5, 2, 1, 1);
+        assert_eq!(metrics.get_github_contributions().len(), 1);
+        assert_eq!(metrics.get_total_offchain_activities(), 1);
+        assert_eq!(metrics.get_offchain_trust_score(), score);
+        
+        // Newer cumulative counts replace older ones
+        metrics.add_github_contribution("polkadot-sdk".to_string(), 1, "Review".to_string(), 7, 1, 3, 1);
+        let contribution = &metrics.get_github_contributions()[0];
+        assert_eq!((contribution.pr_count, contribution.issue_count, contribution.review_count), (7, 2, 3));
+        
+        metrics.add_github_contribution("ink".to_string(), 1, "Issue".to_string(), 0, 1, 0, 1);
+        assert_eq!(metrics.distinct_repos(), 2);
+        assert_eq!(metrics.get_total_offchain_activities(), 2);
+    }
+
     #[test]
     fn test_social_media_content() {
         let mut manager = OffChainTrustManager::new();
@@ -506,6 +730,30 @@ mod tests {
         assert_eq!(metrics.get_total_offchain_activities(), 1);
     }
 
+    #[test]
+    fn test_engagement_decay() {
+        let mut fresh = OffChainSocialTrustMetrics::new(1);
+        fresh.add_social_media_content(1, 1, "Twitter".to_string(), "Thread".to_string(), 0.8, 1000, 10);
+        
+        let mut old = OffChainSocialTrustMetrics::new(2);
+        old.add_social_media_content(1, 2, "Twitter".to_string(), "Thread".to_string(), 0.8, 1000, 10);
+        let now = old.social_media_content[0].timestamp;
+        old.social_media_content[0].timestamp = now - 2 * 365 * 24 * 60 * 60;
+        old.recompute_score(&OffChainWeights::default());
+        
+        assert!((fresh.recent_engagement(now) - 0.8).abs() < 1e-9);
+        assert!(old.recent_engagement(now) < 0.1);
+        assert!(old.get_offchain_trust_score() < fresh.get_offchain_trust_score());
+        
+        // One half-life halves the contribution
+        let half_life = (180 * 24 * 60 * 60) as u64;
+        assert!((fresh.recent_engagement(now + half_life) - 0.4).abs() < 1e-9);
+        
+        // Non-positive half-life disables decay
+        old.recompute_score(&OffChainWeights { engagement_half_life_days: 0.0, ..OffChainWeights::default() });
+        assert!((old.recent_engagement(now) - 0.8).abs() < 1e-9);
+    }
+
     #[test]
     fn test_community_roles() {
         let mut manager = OffChainTrustManager::new();
@@ -536,7 +784,7 @@ mod tests {
             1, 
             "Grant".to_string(), 
             "Web3 Foundation".to_string(), 
-            "Approved".to_string(), 
+            ApprovalStatus::Approved, 
             0.9
         );
         
@@ -544,6 +792,23 @@ mod tests {
         assert_eq!(metrics.get_total_offchain_activities(), 1);
     }
 
+    #[test]
+    fn test_references_by_status() {
+        let mut metrics = OffChainSocialTrustMetrics::new(1);
+        metrics.add_third_party_reference(1, 1, "Grant".to_string(), "Web3 Foundation".to_string(), ApprovalStatus::Approved, 0.9);
+        metrics.add_third_party_reference(2, 1, "Project".to_string(), "Parity".to_string(), ApprovalStatus::Pending, 0.5);
+        metrics.add_third_party_reference(3, 1, "Grant".to_string(), "Treasury".to_string(), ApprovalStatus::Rejected, 0.1);
+        
+        let approved = metrics.references_by_status(ApprovalStatus::Approved);
+        assert_eq!(approved.len(), 1);
+        assert_eq!(approved[0].reference_id, 1);
+        assert_eq!(metrics.references_by_status(ApprovalStatus::Pending).len(), 1);
+        
+        assert_eq!(" APPROVED ".parse::<ApprovalStatus>(), Ok(ApprovalStatus::Approved));
+        assert_eq!("rejected".parse::<ApprovalStatus>(), Ok(ApprovalStatus::Rejected));
+        assert!("maybe".parse::<ApprovalStatus>().is_err());
+    }
+
     #[test]
     fn test_community_voting() {
         let mut manager = OffChainTrustManager::new();
@@ -582,6 +847,60 @@ mod tests {
         assert_eq!(metrics.get_total_offchain_activities(), 1);
     }
 
+    #[test]
+    fn test_offchain_weights() {
+        let mut metrics = OffChainSocialTrustMetrics::new(1);
+        metrics.add_github_contribution("polkadot-sdk".to_string(), 1, "PR".to_string(), 4, 0, 0, 1);
+        
+        // 4 * 0.5 github + 1 * 0.2 recency
+        let default_score = metrics.get_offchain_trust_score();
+        assert!((default_score - 2.2).abs() < 1e-9);
+        
+        let weights = OffChainWeights { github: 2.0, ..OffChainWeights::default() };
+        metrics.recompute_score(&weights);
+        assert!((metrics.get_offchain_trust_score() - 8.2).abs() < 1e-9);
+        
+        metrics.recompute_score(&OffChainWeights::default());
+        assert_eq!(metrics.get_offchain_trust_score(), default_score);
+    }
+
+    #[test]
+    fn test_manager_weights() {
+        let weights = OffChainWeights { polkassembly: 5.0, recency: 0.0, ..OffChainWeights::default() };
+        let mut manager = OffChainTrustManager::with_weights(weights);
+        manager.create_metrics(1);
+        
+        let metrics = manager.metrics.get_mut(&1).unwrap();
+        metrics.add_polkassembly_activity(1, 1, "Post".to_string(), 100, 0, 0);
+        assert_eq!(metrics.get_offchain_trust_score(), 5.0);
+        
+        manager.set_weights(OffChainWeights { polkassembly: 1.0, recency: 0.0, ..OffChainWeights::default() });
+        assert_eq!(manager.get_metrics(1).unwrap().get_offchain_trust_score(), 1.0);
+    }
+
+    #[test]
+    fn test_language_diversity() {
+        let weights = OffChainWeights { recency: 0.0, local_interactions: 0.0, impact: 0.0, ..OffChainWeights::default() };
+        let mut metrics = OffChainSocialTrustMetrics::new(1);
+        metrics.recompute_score(&weights);
+        
+        metrics.add_local_community_interaction(1, 1, "TR".to_string(), "Meetup".to_string(), 50, 0.5);
+        metrics.add_local_community_interaction(2, 1, " tr ".to_string(), "Translation".to_string(), 50, 0.5);
+        assert_eq!(metrics.distinct_languages(), 1);
+        assert_eq!(metrics.get_offchain_trust_score(), 0.0);
+        
+        metrics.add_local_community_interaction(3, 1, "de".to_string(), "Meetup".to_string(), 50, 0.5);
+        assert_eq!(metrics.distinct_languages(), 2);
+        assert_eq!(metrics.get_offchain_trust_score(), 1.0);
+        
+        // Bonus is capped
+        for (id, language) in ["es", "fr", "pt", "ja", "ko"].iter().enumerate() {
+            metrics.add_local_community_interaction(10 + id as u32, 1, language.to_string(), "Meetup".to_string(), 50, 0.5);
+        }
+        assert_eq!(metrics.distinct_languages(), 7);
+        assert_eq!(metrics.get_offchain_trust_score(), weights.max_extra_languages as f64);
+    }
+
     #[test]
     fn test_offchain_trust_score_calculation() {
         let mut manager = OffChainTrustManager::new();
@@ -596,4 +915,18 @@ mod tests {
         // Off-chain trust score should be positive
         assert!(metrics.get_offchain_trust_score() > 0.0);
     }
+
+    #[test]
+    fn test_recent_offchain_activities_window() {
+        let now = 1699430400;
+        let mut metrics = OffChainSocialTrustMetrics::with_clock(1, FixedClock(now - 200 * 24 * 60 * 60));
+        metrics.add_polkassembly_activity(1, 1, "Post".to_string(), 500, 10, 2);
+        metrics.clock = FixedClock(now - 20 * 24 * 60 * 60);
+        metrics.add_polkassembly_activity(2, 1, "Post".to_string(), 500, 10, 2);
+        metrics.clock = FixedClock(now);
+
+        assert_eq!(metrics.get_recent_offchain_activities_count(), 1);
+        assert_eq!(metrics.get_recent_offchain_activities_count_within(7 * 24 * 60 * 60), 0);
+        assert_eq!(metrics.get_recent_offchain_activities_count_within(365 * 24 * 60 * 60), 2);
+    }
 }
@@ -1,8 +1,34 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+use serde::{Deserialize, Serialize};
+
+// Window counted by the trust score's recency factor (30 days in seconds)
+pub const RECENT_ACTIVITY_WINDOW_SECS: u64 = 30 * 24 * 60 * 60;
+
+// Highest conviction level; larger values are treated as this
+pub const MAX_CONVICTION: u8 = 6;
+
+// Voting power multiplier for a conviction level: 0.1x with no conviction,
+// otherwise 1x-6x
+pub fn conviction_multiplier(conviction: u8) -> f64 {
+    match conviction.min(MAX_CONVICTION) {
+        0 => 0.1,
+        level => level as f64,
+    }
+}
 
 // Participation types for tracking different activities
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParticipationType {
     ReferendumVoting,          // Referendum voting participation
     TreasuryProposalSeconding, // Treasury proposal seconding/endorsement
@@ -16,7 +42,7 @@ pub enum ParticipationType {
 }
 
 // Referendum voting participation record
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReferendumParticipation {
     pub referendum_id: u32,        // Referendum ID
     pub voted: bool,               // Whether voted
@@ -27,7 +53,7 @@ pub struct ReferendumParticipation {
 }
 
 // Treasury proposal seconding/endorsement record
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreasurySeconding {
     pub proposal_id: u32,          // Proposal ID
     pub seconder: u32,             // Seconding account
@@ -36,7 +62,7 @@ pub struct TreasurySeconding {
 }
 
 // Treasury tips and bounties contribution record
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreasuryContribution {
     pub contribution_id: u32,      // Contribution ID
     pub contributor: u32,          // Contributor account
@@ -46,7 +72,7 @@ pub struct TreasuryContribution {
 }
 
 // Validator/Nominator support history
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorNominatorHistory {
     pub validator_id: u32,         // Validator ID
     pub nominator_id: u32,         // Nominator ID
@@ -57,7 +83,7 @@ pub struct ValidatorNominatorHistory {
 }
 
 // Reward payout and stake history
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RewardStakeHistory {
     pub account_id: u32,           // Account ID
     pub reward_amount: u128,       // Reward amount
@@ -67,7 +93,7 @@ pub struct RewardStakeHistory {
 }
 
 // Slashing or penalty history
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlashingHistory {
     pub account_id: u32,           // Account ID
     pub slash_amount: u128,        // Slashed amount
@@ -78,7 +104,7 @@ pub struct SlashingHistory {
 }
 
 // Proposal extrinsic history
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProposalExtrinsicHistory {
     pub extrinsic_id: u32,         // Extrinsic ID
     pub account_id: u32,           // Account ID
@@ -88,7 +114,7 @@ pub struct ProposalExtrinsicHistory {
 }
 
 // OpenGov/Track based participation metrics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenGovParticipation {
     pub track_id: u32,             // Track ID
     pub account_id: u32,           // Account ID
@@ -98,7 +124,7 @@ pub struct OpenGovParticipation {
 }
 
 // Delegation history (giving/receiving)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelegationHistory {
     pub delegator: u32,            // Delegator account
     pub delegatee: u32,            // Delegatee account
@@ -110,8 +136,9 @@ pub struct DelegationHistory {
 }
 
 // Social trust score metrics
-#[derive(Debug, Clone)]
-pub struct SocialTrustMetrics {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = "C: Default"))]
+pub struct SocialTrustMetrics<C = DefaultClock> {
     pub account_id: u32,                           // Account ID
     pub total_participations: u32,                 // Total participations
     pub referendum_votes: Vec<ReferendumParticipation>, // Referendum voting history
@@ -125,14 +152,21 @@ pub struct SocialTrustMetrics {
     pub delegation_history: Vec<DelegationHistory>,      // Delegation history
     pub trust_score: f64,                            // Overall trust score
     pub last_updated: u64,                           // Last update timestamp
+    #[serde(skip)]
+    pub clock: C,                                    // Time source
 }
 
-impl SocialTrustMetrics {
+#[cfg(feature = "std")]
+impl SocialTrustMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> SocialTrustMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         SocialTrustMetrics {
             account_id,
@@ -148,16 +182,14 @@ impl SocialTrustMetrics {
             delegation_history: Vec::new(),
             trust_score: 0.0,
             last_updated: now,
+            clock,
         }
     }
 
     // Referendum Voting Participation
     pub fn add_referendum_vote(&mut self, referendum_id: u32, voted: bool, vote_type: Option<String>, 
                               balance: u128, conviction: u8) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let vote = ReferendumParticipation {
             referendum_id,
@@ -179,10 +211,7 @@ impl SocialTrustMetrics {
 
     // Treasury Proposal Seconding / Endorsement
     pub fn add_treasury_seconding(&mut self, proposal_id: u32, seconder: u32, deposit: u128) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let seconding = TreasurySeconding {
             proposal_id,
@@ -203,10 +232,7 @@ impl SocialTrustMetrics {
     // Treasury Tips and Bounties Contributions
     pub fn add_treasury_contribution(&mut self, contribution_id: u32, contributor: u32, 
                                    amount: u128, contribution_type: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let contribution = TreasuryContribution {
             contribution_id,
@@ -228,10 +254,7 @@ impl SocialTrustMetrics {
     // Validator / Nominator History
     pub fn add_validator_nominator_support(&mut self, validator_id: u32, nominator_id: u32, 
                                          amount: u128, is_active: bool) {
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let start_time = self.clock.now_secs();
             
         let support = ValidatorNominatorHistory {
             validator_id,
@@ -254,10 +277,7 @@ impl SocialTrustMetrics {
     // Reward (payout) and Stake History
     pub fn add_reward_stake_history(&mut self, account_id: u32, reward_amount: u128, 
                                   stake_amount: u128, session_index: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let history = RewardStakeHistory {
             account_id,
@@ -279,10 +299,7 @@ impl SocialTrustMetrics {
     // Slashing or Penalty History
     pub fn add_slashing_history(&mut self, account_id: u32, slash_amount: u128, 
                               slash_type: String, reason: String, session_index: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let slashing = SlashingHistory {
             account_id,
@@ -305,10 +322,7 @@ impl SocialTrustMetrics {
     // Proposal Extrinsic History
     pub fn add_proposal_extrinsic_history(&mut self, extrinsic_id: u32, account_id: u32, 
                                         extrinsic_type: String, data: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let extrinsic = ProposalExtrinsicHistory {
             extrinsic_id,
@@ -330,10 +344,7 @@ impl SocialTrustMetrics {
     // OpenGov / Track based participation metrics
     pub fn add_opengov_participation(&mut self, track_id: u32, account_id: u32, 
                                    participation_type: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         // Check if participation already exists for this track and type
         let mut found = false;
@@ -368,10 +379,7 @@ impl SocialTrustMetrics {
     // Delegation giving/receiving history
     pub fn add_delegation_history(&mut self, delegator: u32, delegatee: u32, amount: u128, 
                                 delegation_type: String) {
-        let start_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let start_time = self.clock.now_secs();
             
         let delegation = DelegationHistory {
             delegator,
@@ -392,18 +400,22 @@ impl SocialTrustMetrics {
         &self.delegation_history
     }
 
+    // Referendum vote contribution: 0.5 per vote scaled by its conviction
+    pub fn conviction_weighted_vote_score(&self) -> f64 {
+        self.referendum_votes.iter()
+            .map(|v| 0.5 * conviction_multiplier(v.conviction))
+            .sum()
+    }
+
     // Update trust score based on participation metrics
     fn update_trust_score(&mut self) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let now = self.clock.now_secs();
             
         // Base score calculation based on various factors
         let mut score = 0.0;
         
         // Positive contributions
-        score += self.referendum_votes.len() as f64 * 0.5;
+        score += self.conviction_weighted_vote_score();
         score += self.treasury_secondings.len() as f64 * 1.0;
         score += self.treasury_contributions.len() as f64 * 1.5;
         score += self.validator_nominator_history.len() as f64 * 1.0;
@@ -416,7 +428,7 @@ impl SocialTrustMetrics {
         score -= self.slashing_history.len() as f64 * 2.0;
         
         // Recency factor - more recent activities get higher weight
-        let recent_activities = self.get_recent_activities_count(now);
+        let recent_activities = self.get_recent_activities_count();
         score += recent_activities as f64 * 0.1;
         
         // Normalize score to 0-100 range
@@ -425,20 +437,25 @@ impl SocialTrustMetrics {
     }
 
     // Get count of recent activities (within last 30 days)
-    fn get_recent_activities_count(&self, current_time: u64) -> u32 {
-        let thirty_days_ago = current_time - (30 * 24 * 60 * 60); // 30 days in seconds
+    pub fn get_recent_activities_count(&self) -> u32 {
+        self.get_recent_activities_count_within(RECENT_ACTIVITY_WINDOW_SECS)
+    }
+
+    // Get count of activities within the given window (seconds) before now
+    pub fn get_recent_activities_count_within(&self, window_secs: u64) -> u32 {
+        let cutoff = self.clock.now_secs().saturating_sub(window_secs);
         let mut count = 0;
         
         count += self.referendum_votes.iter()
-            .filter(|v| v.timestamp > thirty_days_ago).count();
+            .filter(|v| v.timestamp > cutoff).count();
         count += self.treasury_secondings.iter()
-            .filter(|s| s.timestamp > thirty_days_ago).count();
+            .filter(|s| s.timestamp > cutoff).count();
         count += self.treasury_contributions.iter()
-            .filter(|c| c.timestamp > thirty_days_ago).count();
+            .filter(|c| c.timestamp > cutoff).count();
         count += self.proposal_extrinsic_history.iter()
-            .filter(|e| e.timestamp > thirty_days_ago).count();
+            .filter(|e| e.timestamp > cutoff).count();
         count += self.opengov_participation.iter()
-            .filter(|p| p.last_participation > thirty_days_ago).map(|p| p.count).sum::<u32>() as usize;
+            .filter(|p| p.last_participation > cutoff).map(|p| p.count).sum::<u32>() as usize;
             
         count as u32
     }
@@ -455,35 +472,101 @@ impl SocialTrustMetrics {
 }
 
 // Social trust metrics manager
-pub struct SocialTrustManager {
-    pub metrics: HashMap<u32, SocialTrustMetrics>, // Account ID -> Metrics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = "C: Default"))]
+pub struct SocialTrustManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, SocialTrustMetrics<C>>, // Account ID -> Metrics
+    #[serde(skip)]
+    pub clock: C,                                     // Time source for new metrics
 }
 
-impl SocialTrustManager {
+#[cfg(feature = "std")]
+impl SocialTrustManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> SocialTrustManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         SocialTrustManager {
             metrics: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &SocialTrustMetrics {
-        let metrics = SocialTrustMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &SocialTrustMetrics<C> {
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| SocialTrustMetrics::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&SocialTrustMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&SocialTrustMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, SocialTrustMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, SocialTrustMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: SocialTrustMetrics<C>) -> Option<SocialTrustMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<SocialTrustMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
+
+    // Leaderboard: (account_id, trust_score) sorted by score descending,
+    // ties broken by ascending account ID
+    pub fn snapshot(&self) -> Vec<(u32, f64)> {
+        let mut entries: Vec<(u32, f64)> = self.metrics
+            .iter()
+            .map(|(&account_id, metrics)| (account_id, metrics.get_trust_score()))
+            .collect();
+        
+        entries.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        entries
+    }
+
+    // Top n accounts by trust score (ties: ascending account ID)
+    pub fn top_accounts_by_score(&self, n: usize) -> Vec<(u32, f64)> {
+        let mut entries = self.snapshot();
+        entries.truncate(n);
+        entries
+    }
+
+    // Export all metrics as JSON
+    #[cfg(feature = "std")]
+    pub fn to_json(&self) -> Result<String, &'static str> {
+        serde_json::to_string(&self.metrics)
+            .map_err(|_| "JSON serialization failed")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut manager = SocialTrustManager::with_clock(FixedClock(1699430400));
+        manager.create_metrics(1);
+        
+        let metrics = manager.metrics.get_mut(&1).unwrap();
+        assert_eq!(metrics.last_updated, 1699430400);
+        
+        metrics.clock = FixedClock(1699430500);
+        metrics.add_treasury_seconding(7, 1, 100);
+        assert_eq!(metrics.treasury_secondings[0].timestamp, 1699430500);
+    }
+
+    crate::manager_tests!(SocialTrustManager, SocialTrustMetrics, total_participations);
 
     #[test]
     fn test_social_trust_metrics_creation() {
@@ -614,6 +697,42 @@ mod tests {
         assert_eq!(metrics.get_total_participations(), 2);
     }
 
+    #[test]
+    fn test_snapshot_ordering() {
+        let mut manager = SocialTrustManager::new();
+        for account_id in [4, 2, 3, 1] {
+            manager.create_metrics(account_id);
+        }
+        
+        manager.metrics.get_mut(&1).unwrap().trust_score = 10.0;
+        manager.metrics.get_mut(&2).unwrap().trust_score = 50.0;
+        manager.metrics.get_mut(&3).unwrap().trust_score = 10.0;
+        manager.metrics.get_mut(&4).unwrap().trust_score = 75.5;
+        
+        let snapshot = manager.snapshot();
+        assert_eq!(snapshot, vec![(4, 75.5), (2, 50.0), (1, 10.0), (3, 10.0)]);
+        
+        assert!(SocialTrustManager::new().snapshot().is_empty());
+        
+        assert_eq!(manager.top_accounts_by_score(2), vec![(4, 75.5), (2, 50.0)]);
+        assert_eq!(manager.top_accounts_by_score(10).len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_manager_to_json() {
+        let mut manager = SocialTrustManager::new();
+        manager.create_metrics(7);
+        manager.metrics.get_mut(&7).unwrap().add_referendum_vote(1, true, Some("Aye".to_string()), 1000, 1);
+        
+        let json = manager.to_json().unwrap();
+        let restored: HashMap<u32, SocialTrustMetrics> = serde_json::from_str(&json).unwrap();
+        
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[&7].referendum_votes.len(), 1);
+        assert_eq!(restored[&7].trust_score, manager.metrics[&7].trust_score);
+    }
+
     #[test]
     fn test_trust_score_calculation() {
         let mut manager = SocialTrustManager::new();
@@ -629,4 +748,39 @@ mod tests {
         // Trust score should be positive
         assert!(metrics.get_trust_score() > 0.0);
     }
+
+    #[test]
+    fn test_recent_activities_window() {
+        let now = 1699430400;
+        let mut metrics = SocialTrustMetrics::with_clock(1, FixedClock(now - 60 * 24 * 60 * 60));
+        metrics.add_referendum_vote(1, true, None, 100, 1);
+        metrics.clock = FixedClock(now - 10 * 24 * 60 * 60);
+        metrics.add_referendum_vote(2, true, None, 100, 1);
+        metrics.clock = FixedClock(now);
+
+        assert_eq!(metrics.get_recent_activities_count(), 1);
+        assert_eq!(metrics.get_recent_activities_count_within(7 * 24 * 60 * 60), 0);
+        assert_eq!(metrics.get_recent_activities_count_within(90 * 24 * 60 * 60), 2);
+        assert_eq!(metrics.get_recent_activities_count_within(u64::MAX), 2);
+    }
+
+    #[test]
+    fn test_conviction_weighted_vote_score() {
+        let mut low = SocialTrustMetrics::with_clock(1, FixedClock(1699430400));
+        low.add_referendum_vote(1, true, None, 100, 1);
+        let mut high = SocialTrustMetrics::with_clock(2, FixedClock(1699430400));
+        high.add_referendum_vote(1, true, None, 100, 6);
+
+        assert_eq!(low.conviction_weighted_vote_score(), 0.5);
+        assert_eq!(high.conviction_weighted_vote_score(), 3.0);
+        assert!(high.get_trust_score() > low.get_trust_score());
+        assert!((high.get_trust_score() - low.get_trust_score() - 2.5).abs() < 1e-9);
+
+        // Out-of-range convictions count as the maximum
+        let mut clamped = SocialTrustMetrics::with_clock(3, FixedClock(1699430400));
+        clamped.add_referendum_vote(1, true, None, 100, 200);
+        assert_eq!(clamped.conviction_weighted_vote_score(), 3.0);
+
+        assert_eq!(conviction_multiplier(0), 0.1);
+    }
 }
@@ -2,8 +2,24 @@
 
 #[ink::contract]
 pub mod reputation_registry {
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    /// Number of past (timestamp, total_score) entries kept per account.
+    pub const SCORE_HISTORY_LEN: usize = 8;
+
+    /// Maximum number of accounts accepted by one `set_scores_batch` call,
+    /// keeping the call well within block weight limits.
+    pub const MAX_SCORE_BATCH_SIZE: usize = 50;
+
+    /// Caps on the two halves of the derived staking score, matching the
+    /// off-chain `StakingScoreMetric` (amount up to 60, duration up to 40).
+    pub const STAKE_AMOUNT_SCORE_CAP: u32 = 60;
+    pub const STAKE_DURATION_SCORE_CAP: u32 = 40;
+
+    /// ln(2) * 10^12, used to turn a fixed-point log2 into ln.
+    const LN_2_E12: u128 = 693_147_180_560;
+
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Role {
@@ -35,6 +51,17 @@ pub mod reputation_registry {
         pub is_active: bool,
     }
 
+    /// Kind of governance activity. Encoded as a single byte with the same
+    /// values the former `action_type: u8` used (1 = vote, 2 = proposal).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum GovernanceAction {
+        #[codec(index = 1)]
+        Vote,
+        #[codec(index = 2)]
+        Proposal,
+    }
+
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct GovernanceRecord {
@@ -50,8 +77,14 @@ pub mod reputation_registry {
         stake_records: Mapping<AccountId, StakeRecord>,
         governance_records: Mapping<AccountId, GovernanceRecord>,
         verified_users: Mapping<AccountId, bool>,
+        score_history: Mapping<AccountId, Vec<(u64, u64)>>,
+        scorers: Mapping<AccountId, bool>,
         total_users: u32,
         minimum_score_threshold: u64,
+        /// Maximum age of a score in milliseconds before it is stale;
+        /// 0 disables the check.
+        max_score_age: u64,
+        paused: bool,
     }
 
     #[ink(event)]
@@ -89,7 +122,16 @@ pub mod reputation_registry {
     pub struct GovernanceParticipation {
         #[ink(topic)]
         account: AccountId,
-        action_type: u8,
+        action_type: GovernanceAction,
+        timestamp: u64,
+    }
+
+    #[ink(event)]
+    pub struct GovernanceBatchRecorded {
+        #[ink(topic)]
+        account: AccountId,
+        votes: u32,
+        proposals: u32,
         timestamp: u64,
     }
 
@@ -103,6 +145,9 @@ pub mod reputation_registry {
         InvalidStakeAmount,
         InsufficientReputation,
         AlreadyExists,
+        EmptyBatch,
+        BatchTooLarge,
+        Paused,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -116,8 +161,12 @@ pub mod reputation_registry {
                 stake_records: Mapping::default(),
                 governance_records: Mapping::default(),
                 verified_users: Mapping::default(),
+                score_history: Mapping::default(),
+                scorers: Mapping::default(),
                 total_users: 0,
                 minimum_score_threshold,
+                max_score_age: 0,
+                paused: false,
             }
         }
 
@@ -136,35 +185,40 @@ pub mod reputation_registry {
             identity_score: u32,
             community_score: u32,
         ) -> Result<()> {
-            self.only_owner()?;
-
-            let old_score = self.reputations
-                .get(&account)
-                .map(|r| r.total_score)
-                .unwrap_or(0);
-
-            let reputation = UserReputation {
+            self.when_not_paused()?;
+            self.only_owner_or_scorer()?;
+            self.apply_score(
+                account,
                 total_score,
                 governance_score,
                 staking_score,
                 identity_score,
                 community_score,
-                last_updated: self.env().block_timestamp(),
-                role: self.determine_role(total_score),
-            };
+            );
+            Ok(())
+        }
 
-            self.reputations.insert(account, &reputation);
+        /// Sets scores for up to `MAX_SCORE_BATCH_SIZE` accounts in one call.
+        /// Each entry is (account, total, governance, staking, identity,
+        /// community) and emits its own `ScoreUpdated` event.
+        #[ink(message)]
+        pub fn set_scores_batch(
+            &mut self,
+            scores: Vec<(AccountId, u64, u32, u32, u32, u32)>,
+        ) -> Result<()> {
+            self.when_not_paused()?;
+            self.only_owner_or_scorer()?;
 
-            if old_score == 0 {
-                self.total_users += 1;
+            if scores.is_empty() {
+                return Err(Error::EmptyBatch);
+            }
+            if scores.len() > MAX_SCORE_BATCH_SIZE {
+                return Err(Error::BatchTooLarge);
             }
 
-            self.env().emit_event(ScoreUpdated {
-                account,
-                old_score,
-                new_score: total_score,
-                timestamp: self.env().block_timestamp(),
-            });
+            for (account, total, governance, staking, identity, community) in scores {
+                self.apply_score(account, total, governance, staking, identity, community);
+            }
 
             Ok(())
         }
@@ -174,8 +228,16 @@ pub mod reputation_registry {
             self.reputations.get(&account)
         }
 
+        /// Last `SCORE_HISTORY_LEN` scores set for `account` as
+        /// (timestamp, total_score), oldest first.
+        #[ink(message)]
+        pub fn get_score_history(&self, account: AccountId) -> Vec<(u64, u64)> {
+            self.score_history.get(&account).unwrap_or_default()
+        }
+
         #[ink(message)]
         pub fn verify_user(&mut self, account: AccountId) -> Result<()> {
+            self.when_not_paused()?;
             self.only_owner()?;
 
             self.verified_users.insert(account, &true);
@@ -195,6 +257,7 @@ pub mod reputation_registry {
 
         #[ink(message)]
         pub fn grant_role(&mut self, account: AccountId, role: Role) -> Result<()> {
+            self.when_not_paused()?;
             self.only_owner()?;
 
             let mut reputation = self.reputations
@@ -226,6 +289,7 @@ pub mod reputation_registry {
             amount: Balance,
             duration: u64,
         ) -> Result<()> {
+            self.when_not_paused()?;
             let caller = self.env().caller();
             self.only_verified_user(caller)?;
 
@@ -256,8 +320,36 @@ pub mod reputation_registry {
             self.stake_records.get(&account)
         }
 
+        /// Integer version of the off-chain `StakingScoreMetric`:
+        ///
+        /// - amount: `floor(10 * ln(amount))` from a fixed-point logarithm,
+        ///   capped at `STAKE_AMOUNT_SCORE_CAP`
+        /// - duration (seconds): `5 * floor(sqrt(days))` using whole days,
+        ///   capped at `STAKE_DURATION_SCORE_CAP`
+        ///
+        /// The result is 0-100. Flooring keeps the amount part under 1 point
+        /// below the floating-point metric and the duration part under 5
+        /// points below it, so the total is less than 6 points low.
+        ///
+        /// `record_stake` does not apply it to the stored reputation; scorers
+        /// pass it to `set_score` so total score, role and history stay in step.
+        #[ink(message)]
+        pub fn compute_staking_score(&self, amount: Balance, duration: u64) -> u32 {
+            let amount_score = if amount == 0 {
+                0
+            } else {
+                ten_ln(amount).min(STAKE_AMOUNT_SCORE_CAP)
+            };
+
+            let days = duration / 86_400;
+            let duration_score = (integer_sqrt(days) * 5).min(STAKE_DURATION_SCORE_CAP as u64) as u32;
+
+            amount_score + duration_score
+        }
+
         #[ink(message)]
         pub fn unstake(&mut self) -> Result<()> {
+            self.when_not_paused()?;
             let caller = self.env().caller();
             
             let mut stake = self.stake_records
@@ -272,6 +364,7 @@ pub mod reputation_registry {
 
         #[ink(message)]
         pub fn record_governance_vote(&mut self) -> Result<()> {
+            self.when_not_paused()?;
             let caller = self.env().caller();
             self.only_verified_user(caller)?;
 
@@ -289,7 +382,7 @@ pub mod reputation_registry {
 
             self.env().emit_event(GovernanceParticipation {
                 account: caller,
-                action_type: 1,
+                action_type: GovernanceAction::Vote,
                 timestamp: self.env().block_timestamp(),
             });
 
@@ -298,6 +391,7 @@ pub mod reputation_registry {
 
         #[ink(message)]
         pub fn record_governance_proposal(&mut self) -> Result<()> {
+            self.when_not_paused()?;
             let caller = self.env().caller();
             self.only_verified_user(caller)?;
 
@@ -315,7 +409,42 @@ pub mod reputation_registry {
 
             self.env().emit_event(GovernanceParticipation {
                 account: caller,
-                action_type: 2,
+                action_type: GovernanceAction::Proposal,
+                timestamp: self.env().block_timestamp(),
+            });
+
+            Ok(())
+        }
+
+        /// Records aggregated governance activity computed off-chain in one
+        /// call, emitting a single `GovernanceBatchRecorded` event.
+        #[ink(message)]
+        pub fn batch_record_governance(&mut self, votes: u32, proposals: u32) -> Result<()> {
+            self.when_not_paused()?;
+            let caller = self.env().caller();
+            self.only_verified_user(caller)?;
+
+            if votes == 0 && proposals == 0 {
+                return Err(Error::EmptyBatch);
+            }
+
+            let mut record = self.governance_records
+                .get(&caller)
+                .unwrap_or(GovernanceRecord {
+                    votes_count: 0,
+                    proposals_count: 0,
+                    last_participation: 0,
+                });
+
+            record.votes_count = record.votes_count.saturating_add(votes);
+            record.proposals_count = record.proposals_count.saturating_add(proposals);
+            record.last_participation = self.env().block_timestamp();
+            self.governance_records.insert(caller, &record);
+
+            self.env().emit_event(GovernanceBatchRecorded {
+                account: caller,
+                votes,
+                proposals,
                 timestamp: self.env().block_timestamp(),
             });
 
@@ -330,12 +459,23 @@ pub mod reputation_registry {
         #[ink(message)]
         pub fn check_access(&self, account: AccountId, required_score: u64) -> bool {
             if let Some(reputation) = self.reputations.get(&account) {
-                reputation.total_score >= required_score
+                reputation.total_score >= required_score && !self.is_expired(&reputation)
             } else {
                 false
             }
         }
 
+        /// True if `account` has no score, or its score is older than
+        /// `max_score_age`. Always false for scored accounts while the age
+        /// limit is disabled.
+        #[ink(message)]
+        pub fn is_stale(&self, account: AccountId) -> bool {
+            match self.reputations.get(&account) {
+                Some(reputation) => self.is_expired(&reputation),
+                None => true,
+            }
+        }
+
         #[ink(message)]
         pub fn is_governance_participant(&self, account: AccountId) -> bool {
             if let Some(record) = self.governance_records.get(&account) {
@@ -362,6 +502,20 @@ pub mod reputation_registry {
             self.minimum_score_threshold
         }
 
+        /// Sets the maximum score age in milliseconds (block timestamp
+        /// units); 0 disables the staleness check.
+        #[ink(message)]
+        pub fn update_max_score_age(&mut self, max_score_age: u64) -> Result<()> {
+            self.only_owner()?;
+            self.max_score_age = max_score_age;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_max_score_age(&self) -> u64 {
+            self.max_score_age
+        }
+
         #[ink(message)]
         pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
             self.only_owner()?;
@@ -374,6 +528,106 @@ pub mod reputation_registry {
             self.owner
         }
 
+        /// Authorizes `account` to call `set_score` alongside the owner.
+        #[ink(message)]
+        pub fn add_scorer(&mut self, account: AccountId) -> Result<()> {
+            self.only_owner()?;
+            self.scorers.insert(account, &true);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_scorer(&mut self, account: AccountId) -> Result<()> {
+            self.only_owner()?;
+            self.scorers.remove(account);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_scorer(&self, account: AccountId) -> bool {
+            self.scorers.get(&account).unwrap_or(false)
+        }
+
+        /// Freezes all score, verification, stake and governance updates.
+        /// Getters and owner administration keep working while paused.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            self.only_owner()?;
+            self.paused = true;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            self.only_owner()?;
+            self.paused = false;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        fn apply_score(
+            &mut self,
+            account: AccountId,
+            total_score: u64,
+            governance_score: u32,
+            staking_score: u32,
+            identity_score: u32,
+            community_score: u32,
+        ) {
+            let previous = self.reputations.get(&account);
+            let old_score = previous.as_ref().map(|r| r.total_score).unwrap_or(0);
+
+            let reputation = UserReputation {
+                total_score,
+                governance_score,
+                staking_score,
+                identity_score,
+                community_score,
+                last_updated: self.env().block_timestamp(),
+                role: self.determine_role(total_score),
+            };
+
+            self.reputations.insert(account, &reputation);
+            self.push_score_history(account, reputation.last_updated, total_score);
+
+            if previous.is_none() {
+                self.total_users += 1;
+            }
+
+            self.env().emit_event(ScoreUpdated {
+                account,
+                old_score,
+                new_score: total_score,
+                timestamp: self.env().block_timestamp(),
+            });
+        }
+
+        fn push_score_history(&mut self, account: AccountId, timestamp: u64, total_score: u64) {
+            let mut history = self.score_history.get(&account).unwrap_or_default();
+            if history.len() >= SCORE_HISTORY_LEN {
+                history.remove(0);
+            }
+            history.push((timestamp, total_score));
+            self.score_history.insert(account, &history);
+        }
+
+        fn is_expired(&self, reputation: &UserReputation) -> bool {
+            self.max_score_age > 0
+                && reputation.last_updated.saturating_add(self.max_score_age)
+                    < self.env().block_timestamp()
+        }
+
+        fn when_not_paused(&self) -> Result<()> {
+            if self.paused {
+                return Err(Error::Paused);
+            }
+            Ok(())
+        }
+
         fn only_owner(&self) -> Result<()> {
             if self.env().caller() != self.owner {
                 return Err(Error::Unauthorized);
@@ -381,6 +635,14 @@ pub mod reputation_registry {
             Ok(())
         }
 
+        fn only_owner_or_scorer(&self) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner && !self.is_scorer(caller) {
+                return Err(Error::Unauthorized);
+            }
+            Ok(())
+        }
+
         fn only_verified_user(&self, account: AccountId) -> Result<()> {
             if !self.is_verified(account) {
                 return Err(Error::UserNotVerified);
@@ -409,6 +671,47 @@ pub mod reputation_registry {
         }
     }
 
+    /// `floor(10 * ln(value))` for `value >= 1`, from a binary logarithm
+    /// with 32 fractional bits computed by repeated squaring.
+    fn ten_ln(value: u128) -> u32 {
+        const FRAC_BITS: u32 = 32;
+        const ONE: u128 = 1 << 62;
+
+        let int_part = 127 - value.leading_zeros();
+        // Mantissa value / 2^int_part in [1, 2), as a Q62 number
+        let mut y = if int_part <= 62 {
+            value << (62 - int_part)
+        } else {
+            value >> (int_part - 62)
+        };
+
+        let mut frac: u128 = 0;
+        for bit in (0..FRAC_BITS).rev() {
+            y = (y * y) >> 62;
+            if y >= 2 * ONE {
+                y >>= 1;
+                frac |= 1 << bit;
+            }
+        }
+
+        let log2 = ((int_part as u128) << FRAC_BITS) | frac;
+        (((log2 * LN_2_E12 * 10) >> FRAC_BITS) / 1_000_000_000_000) as u32
+    }
+
+    /// Floor of the square root, by Newton's method.
+    fn integer_sqrt(value: u64) -> u64 {
+        if value < 2 {
+            return value;
+        }
+        let mut x = value;
+        let mut y = value / 2 + value % 2;
+        while y < x {
+            x = y;
+            y = (x + value / x) / 2;
+        }
+        x
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -442,6 +745,67 @@ pub mod reputation_registry {
             assert_eq!(reputation.community_score, 10);
         }
 
+        #[ink::test]
+        fn batch_score_setting_works() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let _ = contract.set_score(accounts.alice, 40, 10, 10, 10, 10);
+            let events_before = ink::env::test::recorded_events().count();
+
+            let result = contract.set_scores_batch(vec![
+                (accounts.alice, 85, 30, 25, 20, 10),
+                (accounts.bob, 60, 20, 20, 10, 10),
+                (accounts.charlie, 0, 0, 0, 0, 0),
+            ]);
+            assert!(result.is_ok());
+            assert_eq!(ink::env::test::recorded_events().count(), events_before + 3);
+
+            assert_eq!(contract.get_score(accounts.alice).unwrap().total_score, 85);
+            assert_eq!(contract.get_score(accounts.bob).unwrap().total_score, 60);
+            // Alice was already registered; Bob and Charlie are new
+            assert_eq!(contract.get_total_users(), 3);
+
+            // Re-scoring an account whose score was 0 does not count it twice
+            let _ = contract.set_scores_batch(vec![(accounts.charlie, 20, 5, 5, 5, 5)]);
+            assert_eq!(contract.get_total_users(), 3);
+        }
+
+        #[ink::test]
+        fn batch_score_setting_is_bounded() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert_eq!(contract.set_scores_batch(Vec::new()), Err(Error::EmptyBatch));
+
+            let oversized = vec![(accounts.alice, 85, 30, 25, 20, 10); MAX_SCORE_BATCH_SIZE + 1];
+            assert_eq!(contract.set_scores_batch(oversized), Err(Error::BatchTooLarge));
+            assert!(contract.get_score(accounts.alice).is_none());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let result = contract.set_scores_batch(vec![(accounts.alice, 85, 30, 25, 20, 10)]);
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn score_history_is_bounded() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(contract.get_score_history(accounts.alice).is_empty());
+
+            for score in 1..=(SCORE_HISTORY_LEN as u64 + 2) {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(score * 1000);
+                let _ = contract.set_score(accounts.alice, score, 0, 0, 0, 0);
+            }
+
+            let history = contract.get_score_history(accounts.alice);
+            assert_eq!(history.len(), SCORE_HISTORY_LEN);
+            // The two oldest entries were dropped
+            assert_eq!(history.first(), Some(&(3000, 3)));
+            assert_eq!(history.last(), Some(&(10000, 10)));
+        }
+
         #[ink::test]
         fn verify_user_works() {
             let mut contract = ReputationRegistry::new(50);
@@ -465,6 +829,41 @@ pub mod reputation_registry {
             assert_eq!(result, Err(Error::Unauthorized));
         }
 
+        #[ink::test]
+        fn authorized_scorer_can_set_score() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(contract.add_scorer(accounts.bob).is_ok());
+            assert!(contract.is_scorer(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let result = contract.set_score(accounts.alice, 85, 30, 25, 20, 10);
+            assert!(result.is_ok());
+            assert_eq!(contract.get_score(accounts.alice).unwrap().total_score, 85);
+
+            // Scorers cannot manage other scorers
+            assert_eq!(contract.add_scorer(accounts.charlie), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let result = contract.set_score(accounts.alice, 10, 0, 0, 0, 0);
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
+        #[ink::test]
+        fn removed_scorer_cannot_set_score() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let _ = contract.add_scorer(accounts.bob);
+            assert!(contract.remove_scorer(accounts.bob).is_ok());
+            assert!(!contract.is_scorer(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let result = contract.set_score(accounts.alice, 85, 30, 25, 20, 10);
+            assert_eq!(result, Err(Error::Unauthorized));
+        }
+
         #[ink::test]
         fn role_assignment_works() {
             let mut contract = ReputationRegistry::new(50);
@@ -494,6 +893,54 @@ pub mod reputation_registry {
             assert!(stake.is_active);
         }
 
+        #[ink::test]
+        fn staking_score_computation_works() {
+            let contract = ReputationRegistry::new(50);
+
+            assert_eq!(contract.compute_staking_score(0, 0), 0);
+            assert_eq!(contract.compute_staking_score(1, 0), 0);
+            // 10 * ln(1024) = 69.3, capped at 60
+            assert_eq!(contract.compute_staking_score(1024, 0), 60);
+            // 10 * ln(100) = 46.05
+            assert_eq!(contract.compute_staking_score(100, 0), 46);
+            // Matches the floating-point metric below the cap, e.g.
+            // 10 * ln(403) = 59.99
+            for amount in [2u128, 3, 7, 10, 50, 100, 255, 256, 403] {
+                let expected = ((amount as f64).ln() * 10.0).floor() as u32;
+                assert_eq!(contract.compute_staking_score(amount, 0), expected);
+            }
+            // 100 days -> 5 * 10 = 50, capped at 40
+            assert_eq!(contract.compute_staking_score(0, 100 * 86_400), 40);
+            // 16 days -> 20; partial days are ignored
+            assert_eq!(contract.compute_staking_score(0, 16 * 86_400 + 3_600), 20);
+            assert_eq!(contract.compute_staking_score(u128::MAX, u64::MAX), 100);
+        }
+
+        #[ink::test]
+        fn record_stake_leaves_score_to_scorer() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let _ = contract.verify_user(accounts.alice);
+            let _ = contract.set_score(accounts.alice, 85, 30, 0, 20, 10);
+
+            let result = contract.record_stake(1024, 16 * 86_400);
+            assert!(result.is_ok());
+
+            // Components and total only change together, through set_score
+            let reputation = contract.get_score(accounts.alice).unwrap();
+            assert_eq!(reputation.staking_score, 0);
+            assert_eq!(reputation.total_score, 85);
+            assert_eq!(contract.get_score_history(accounts.alice).len(), 1);
+
+            let staking_score = contract.compute_staking_score(1024, 16 * 86_400);
+            let _ = contract.set_score(accounts.alice, 90, 30, staking_score, 20, 10);
+            let reputation = contract.get_score(accounts.alice).unwrap();
+            assert_eq!(reputation.staking_score, 80);
+            assert_eq!(reputation.total_score, 90);
+            assert_eq!(contract.get_score_history(accounts.alice).len(), 2);
+        }
+
         #[ink::test]
         fn governance_vote_recording_works() {
             let mut contract = ReputationRegistry::new(50);
@@ -526,6 +973,39 @@ pub mod reputation_registry {
             assert_eq!(record.proposals_count, 1);
         }
 
+        #[ink::test]
+        fn batch_governance_recording_works() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let _ = contract.verify_user(accounts.alice);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let _ = contract.record_governance_vote();
+            let events_before = ink::env::test::recorded_events().count();
+
+            let result = contract.batch_record_governance(10, 2);
+            assert!(result.is_ok());
+            assert_eq!(ink::env::test::recorded_events().count(), events_before + 1);
+
+            let record = contract.get_governance_record(accounts.alice).unwrap();
+            assert_eq!(record.votes_count, 11);
+            assert_eq!(record.proposals_count, 2);
+
+            assert_eq!(contract.batch_record_governance(0, 0), Err(Error::EmptyBatch));
+        }
+
+        #[ink::test]
+        fn unverified_user_cannot_batch_record() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            let result = contract.batch_record_governance(5, 1);
+            assert_eq!(result, Err(Error::UserNotVerified));
+        }
+
         #[ink::test]
         fn access_check_works() {
             let mut contract = ReputationRegistry::new(50);
@@ -538,6 +1018,35 @@ pub mod reputation_registry {
             assert!(!contract.check_access(accounts.alice, 90));
         }
 
+        #[ink::test]
+        fn stale_score_denies_access() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            assert!(contract.is_stale(accounts.alice));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            let _ = contract.set_score(accounts.alice, 85, 30, 25, 20, 10);
+
+            // Age limit disabled by default
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000_000);
+            assert!(!contract.is_stale(accounts.alice));
+            assert!(contract.check_access(accounts.alice, 50));
+
+            assert!(contract.update_max_score_age(10_000).is_ok());
+            assert_eq!(contract.get_max_score_age(), 10_000);
+            assert!(contract.is_stale(accounts.alice));
+            assert!(!contract.check_access(accounts.alice, 50));
+
+            // Exactly at the limit still counts as fresh
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11_000);
+            assert!(!contract.is_stale(accounts.alice));
+            assert!(contract.check_access(accounts.alice, 50));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.update_max_score_age(0), Err(Error::Unauthorized));
+        }
+
         #[ink::test]
         fn governance_participant_check_works() {
             let mut contract = ReputationRegistry::new(50);
@@ -611,6 +1120,51 @@ pub mod reputation_registry {
             assert_eq!(result, Err(Error::InvalidStakeAmount));
         }
 
+        #[ink::test]
+        fn paused_contract_rejects_mutations() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            let _ = contract.verify_user(accounts.alice);
+            let _ = contract.set_score(accounts.alice, 85, 30, 25, 20, 10);
+
+            assert!(contract.pause().is_ok());
+            assert!(contract.is_paused());
+
+            assert_eq!(contract.set_score(accounts.alice, 10, 0, 0, 0, 0), Err(Error::Paused));
+            assert_eq!(contract.verify_user(accounts.bob), Err(Error::Paused));
+            assert_eq!(contract.record_stake(1000, 86400), Err(Error::Paused));
+            assert_eq!(contract.record_governance_vote(), Err(Error::Paused));
+            assert_eq!(contract.record_governance_proposal(), Err(Error::Paused));
+            assert_eq!(contract.batch_record_governance(1, 1), Err(Error::Paused));
+
+            // Getters still work
+            assert_eq!(contract.get_score(accounts.alice).unwrap().total_score, 85);
+            assert!(contract.check_access(accounts.alice, 50));
+            assert!(contract.is_verified(accounts.alice));
+
+            assert!(contract.unpause().is_ok());
+            assert!(!contract.is_paused());
+            assert!(contract.set_score(accounts.alice, 10, 0, 0, 0, 0).is_ok());
+            assert!(contract.record_governance_vote().is_ok());
+        }
+
+        #[ink::test]
+        fn only_owner_can_pause() {
+            let mut contract = ReputationRegistry::new(50);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.pause(), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let _ = contract.pause();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.unpause(), Err(Error::Unauthorized));
+            assert!(contract.is_paused());
+        }
+
         #[ink::test]
         fn total_users_increments() {
             let mut contract = ReputationRegistry::new(50);
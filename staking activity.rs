@@ -1,8 +1,19 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+use crate::math::safe_ratio;
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
 
 // Staking statuses
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum StakingStatus {
     Active,      // Active staking
     Inactive,    // Inactive staking
@@ -28,7 +39,7 @@ pub enum RewardType {
 
 // Staking activity record
 #[derive(Debug, Clone)]
-pub struct StakingActivity {
+pub struct StakingActivity<C = DefaultClock> {
     pub account_id: u32,           // Account ID
     pub status: StakingStatus,     // Staking status
     pub staked_amount: u128,       // Staked DOT amount
@@ -41,6 +52,7 @@ pub struct StakingActivity {
     pub active_sessions: u32,      // Active session count
     pub total_sessions: u32,       // Total session count
     pub last_active_time: u64,     // Last activity time
+    pub clock: C,                  // Time source
 }
 
 // Reward record
@@ -81,12 +93,17 @@ pub struct NominatorActivity {
     pub active: bool,              // Active status
 }
 
-impl StakingActivity {
+#[cfg(feature = "std")]
+impl StakingActivity<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> StakingActivity<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         StakingActivity {
             account_id,
@@ -101,6 +118,7 @@ impl StakingActivity {
             active_sessions: 0,
             total_sessions: 0,
             last_active_time: now,
+            clock,
         }
     }
 
@@ -112,10 +130,7 @@ impl StakingActivity {
     pub fn set_staking_status(&mut self, status: StakingStatus) {
         self.status = status;
         if status == StakingStatus::Active {
-            self.last_active_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards")
-                .as_secs();
+            self.last_active_time = self.clock.now_secs();
             self.active_sessions += 1;
         }
         self.total_sessions += 1;
@@ -147,11 +162,8 @@ impl StakingActivity {
 
     // Staking Duration
     pub fn staking_duration(&self) -> u64 {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        now - self.start_time
+        let now = self.clock.now_secs();
+        now.saturating_sub(self.start_time)
     }
 
     // Validator Selection
@@ -175,10 +187,7 @@ impl StakingActivity {
 
     // Reward History
     pub fn add_reward(&mut self, amount: u128, reward_type: RewardType, session_index: u32, validator_id: Option<u32>) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let reward = RewardRecord {
             amount,
@@ -201,10 +210,7 @@ impl StakingActivity {
 
     // Unbonding Status
     pub fn start_unbonding(&mut self, amount: u128, unlock_duration: u64) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let now = self.clock.now_secs();
             
         let unbonding = UnbondingRecord {
             amount,
@@ -229,10 +235,7 @@ impl StakingActivity {
 
     // Slashing History
     pub fn add_slash(&mut self, amount: u128, slash_type: SlashType, session_index: u32, reason: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let slash = SlashRecord {
             amount,
@@ -262,10 +265,7 @@ impl StakingActivity {
 
     // Nominator Activity
     pub fn add_nominator_activity(&mut self, nominator_id: u32, nominated_amount: u128) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = NominatorActivity {
             nominator_id,
@@ -287,10 +287,7 @@ impl StakingActivity {
 
     // Activity Frequency
     pub fn activity_frequency(&self) -> f64 {
-        if self.total_sessions == 0 {
-            return 0.0;
-        }
-        self.active_sessions as f64 / self.total_sessions as f64
+        safe_ratio(self.active_sessions as f64, self.total_sessions as f64)
     }
 
     pub fn get_active_sessions(&self) -> u32 {
@@ -313,10 +310,7 @@ impl StakingActivity {
             self.set_staking_status(StakingStatus::Active);
         }
         
-        self.last_active_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        self.last_active_time = self.clock.now_secs();
     }
 
     pub fn chill_staking(&mut self) {
@@ -363,33 +357,55 @@ impl Validator {
 }
 
 // Staking manager
-pub struct StakingManager {
-    pub staking_activities: HashMap<u32, StakingActivity>,
+pub struct StakingManager<C = DefaultClock> {
+    pub staking_activities: HashMap<u32, StakingActivity<C>>,
     pub validators: HashMap<u32, Validator>,
+    pub clock: C,
 }
 
-impl StakingManager {
+#[cfg(feature = "std")]
+impl StakingManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> StakingManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         StakingManager {
             staking_activities: HashMap::new(),
             validators: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_staking_activity(&mut self, account_id: u32) -> &StakingActivity {
-        let activity = StakingActivity::new(account_id);
-        self.staking_activities.insert(account_id, activity);
-        self.staking_activities.get(&account_id).unwrap()
+    // Get or create staking activity; an existing account's data is returned untouched
+    pub fn create_staking_activity(&mut self, account_id: u32) -> &StakingActivity<C> {
+        self.staking_activities
+            .entry(account_id)
+            .or_insert_with(|| StakingActivity::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_staking_activity(&self, account_id: u32) -> Option<&StakingActivity> {
+    pub fn get_staking_activity(&self, account_id: u32) -> Option<&StakingActivity<C>> {
         self.staking_activities.get(&account_id)
     }
 
+    // Insert or replace staking activity, returning the previous entry if any
+    pub fn insert_staking_activity(&mut self, account_id: u32, activity: StakingActivity<C>) -> Option<StakingActivity<C>> {
+        self.staking_activities.insert(account_id, activity)
+    }
+
+    // Remove an account's staking activity
+    pub fn remove_staking_activity(&mut self, account_id: u32) -> Option<StakingActivity<C>> {
+        self.staking_activities.remove(&account_id)
+    }
+
+    // Get or create validator; commission is ignored if the validator already exists
     pub fn create_validator(&mut self, id: u32, commission: f64) -> &Validator {
-        let validator = Validator::new(id, commission);
-        self.validators.insert(id, validator);
-        self.validators.get(&id).unwrap()
+        self.validators
+            .entry(id)
+            .or_insert_with(|| Validator::new(id, commission))
     }
 
     pub fn get_validator(&self, id: u32) -> Option<&Validator> {
@@ -400,6 +416,11 @@ impl StakingManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    crate::manager_tests!(StakingManager, StakingActivity, staked_amount,
+                          staking_activities, create_staking_activity, get_staking_activity,
+                          insert_staking_activity, remove_staking_activity);
 
     #[test]
     fn test_staking_activity_creation() {
@@ -411,6 +432,42 @@ mod tests {
         assert_eq!(activity.staked_amount, 0);
     }
 
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut manager = StakingManager::with_clock(FixedClock(1699430400));
+        manager.create_staking_activity(1);
+        
+        let activity = manager.staking_activities.get_mut(&1).unwrap();
+        assert_eq!(activity.start_time, 1699430400);
+        
+        activity.clock = FixedClock(1699430400 + 3600);
+        assert_eq!(activity.staking_duration(), 3600);
+    }
+
+    #[test]
+    fn test_clock_moved_backwards() {
+        let mut manager = StakingManager::with_clock(FixedClock(1699430400));
+        manager.create_staking_activity(1);
+        
+        let activity = manager.staking_activities.get_mut(&1).unwrap();
+        activity.clock = FixedClock(1699430400 - 3600);
+        assert_eq!(activity.staking_duration(), 0);
+    }
+
+    #[test]
+    fn test_create_keeps_existing() {
+        let mut manager = StakingManager::new();
+        manager.create_staking_activity(1);
+        manager.staking_activities.get_mut(&1).unwrap().stake_amount(1000);
+        
+        let activity = manager.create_staking_activity(1);
+        assert_eq!(activity.staked_amount, 1000);
+        
+        manager.create_validator(7, 0.05);
+        let validator = manager.create_validator(7, 0.5);
+        assert_eq!(validator.commission, 0.05);
+    }
+
     #[test]
     fn test_staking_amount() {
         let mut manager = StakingManager::new();
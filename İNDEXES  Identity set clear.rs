@@ -1,8 +1,18 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::{String, ToString}, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
 
 // Identity field types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IdentityField {
     Display,
     Legal,
@@ -59,7 +69,7 @@ pub struct IdentityActivity {
 
 // Identity metrics
 #[derive(Debug, Clone)]
-pub struct IdentityMetrics {
+pub struct IdentityMetrics<C = DefaultClock> {
     pub account_id: u32,
     pub is_identity_set: bool,              // Is identity defined?
     pub is_identity_cleared: bool,          // Is identity removed?
@@ -74,14 +84,20 @@ pub struct IdentityMetrics {
     pub social_media_links: u32,            // Social media connections count
     pub identity_activities: Vec<IdentityActivity>, // Identity activity history
     pub last_activity_time: u64,            // Last activity timestamp
+    pub clock: C,                           // Time source
 }
 
-impl IdentityMetrics {
+#[cfg(feature = "std")]
+impl IdentityMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> IdentityMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         IdentityMetrics {
             account_id,
@@ -98,6 +114,7 @@ impl IdentityMetrics {
             social_media_links: 0,
             identity_activities: Vec::new(),
             last_activity_time: now,
+            clock,
         }
     }
 
@@ -108,10 +125,7 @@ impl IdentityMetrics {
 
     // Set identity with initial fields
     pub fn set_identity(&mut self, fields: Vec<(IdentityField, String)>, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         // Set identity creation time
         if self.identity_creation_time.is_none() {
@@ -143,10 +157,7 @@ impl IdentityMetrics {
 
     // Clear identity
     pub fn clear_identity(&mut self, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         self.is_identity_cleared = true;
         self.is_identity_set = false;
@@ -170,11 +181,8 @@ impl IdentityMetrics {
     pub fn get_identity_active_duration(&self) -> Option<u64> {
         if let Some(creation_time) = self.identity_creation_time {
             if !self.is_identity_cleared && !self.is_identity_killed {
-                let now = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Time went backwards")
-                    .as_secs();
-                Some(now - creation_time)
+                let now = self.clock.now_secs();
+                Some(now.saturating_sub(creation_time))
             } else {
                 None // Identity is not active
             }
@@ -230,18 +238,12 @@ impl IdentityMetrics {
     // 8. How long have fields remained unchanged on-chain?
     pub fn get_fields_unchanged_duration(&self) -> Option<u64> {
         if let Some(last_change) = self.last_field_change_time {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards")
-                .as_secs();
-            Some(now - last_change)
+            let now = self.clock.now_secs();
+            Some(now.saturating_sub(last_change))
         } else {
             self.identity_creation_time.map(|creation_time| {
-                let now = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Time went backwards")
-                    .as_secs();
-                now - creation_time
+                let now = self.clock.now_secs();
+                now.saturating_sub(creation_time)
             })
         }
     }
@@ -253,10 +255,7 @@ impl IdentityMetrics {
 
     // Kill identity
     pub fn kill_identity(&mut self, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         self.is_identity_killed = true;
         self.is_identity_set = false;
@@ -336,10 +335,7 @@ impl IdentityMetrics {
 
     // Update identity field
     pub fn update_field(&mut self, field_type: IdentityField, value: String, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         self.add_or_update_field(field_type, value, timestamp);
         
@@ -438,35 +434,82 @@ impl IdentityMetrics {
 }
 
 // Identity metrics manager
-pub struct IdentityMetricsManager {
-    pub metrics: HashMap<u32, IdentityMetrics>, // Account ID -> Metrics
+pub struct IdentityMetricsManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, IdentityMetrics<C>>, // Account ID -> Metrics
+    pub clock: C,                                  // Time source for new metrics
 }
 
-impl IdentityMetricsManager {
+#[cfg(feature = "std")]
+impl IdentityMetricsManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> IdentityMetricsManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         IdentityMetricsManager {
             metrics: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &IdentityMetrics {
-        let metrics = IdentityMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &IdentityMetrics<C> {
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| IdentityMetrics::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&IdentityMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&IdentityMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, IdentityMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, IdentityMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: IdentityMetrics<C>) -> Option<IdentityMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<IdentityMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut metrics = IdentityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.set_identity(vec![(IdentityField::Display, "Alice".to_string())], 100);
+        assert_eq!(metrics.identity_creation_time, Some(1699430400));
+        
+        metrics.clock = FixedClock(1699430400 + 600);
+        assert_eq!(metrics.get_fields_unchanged_duration(), Some(600));
+    }
+
+    #[test]
+    fn test_clock_moved_backwards() {
+        let mut metrics = IdentityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.set_identity(vec![(IdentityField::Display, "Alice".to_string())], 100);
+        
+        metrics.clock = FixedClock(1699430400 - 600);
+        assert_eq!(metrics.get_identity_active_duration(), Some(0));
+        assert_eq!(metrics.get_fields_unchanged_duration(), Some(0));
+        
+        metrics.last_field_change_time = Some(1699430400);
+        assert_eq!(metrics.get_fields_unchanged_duration(), Some(0));
+    }
+
+    crate::manager_tests!(IdentityMetricsManager, IdentityMetrics, total_fields_filled);
 
     #[test]
     fn test_identity_metrics_creation() {
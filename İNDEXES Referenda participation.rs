@@ -1,5 +1,18 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
+
+// Default window for recent activity counts (90 days in seconds)
+pub const RECENT_ACTIVITY_WINDOW_SECS: u64 = 90 * 24 * 60 * 60;
 
 // Vote types
 #[derive(Debug, Clone, PartialEq)]
@@ -10,7 +23,7 @@ pub enum VoteType {
 }
 
 // Conviction levels (multiplier for voting power)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Conviction {
     None,    // 0.1x voting power, no lock
     Locked1x,  // 1x voting power, locked for 1x period
@@ -22,7 +35,7 @@ pub enum Conviction {
 }
 
 // Governance tracks (different governance areas)
-#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum GovernanceTrack {
     Root,              // Root track
     Whitelist,         // Whitelist track
@@ -33,6 +46,47 @@ pub enum GovernanceTrack {
     Custom(String),    // Custom track
 }
 
+// Per-track weights for weighted track diversity
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackWeights {
+    pub root: f64,                 // Root track weight
+    pub whitelist: f64,            // Whitelist track weight
+    pub treasury: f64,             // Treasury track weight
+    pub staking: f64,              // Staking track weight
+    pub governance: f64,           // General governance track weight
+    pub fellowship: f64,           // Fellowship track weight
+    pub default_weight: f64,       // Fallback weight for custom tracks
+}
+
+impl Default for TrackWeights {
+    fn default() -> Self {
+        TrackWeights {
+            root: 3.0,
+            whitelist: 1.5,
+            treasury: 2.0,
+            staking: 1.0,
+            governance: 1.0,
+            fellowship: 1.0,
+            default_weight: 1.0,
+        }
+    }
+}
+
+impl TrackWeights {
+    // Weight for a single track (custom tracks use the default weight)
+    pub fn weight_for(&self, track: &GovernanceTrack) -> f64 {
+        match track {
+            GovernanceTrack::Root => self.root,
+            GovernanceTrack::Whitelist => self.whitelist,
+            GovernanceTrack::Treasury => self.treasury,
+            GovernanceTrack::Staking => self.staking,
+            GovernanceTrack::Governance => self.governance,
+            GovernanceTrack::Fellowship => self.fellowship,
+            GovernanceTrack::Custom(_) => self.default_weight,
+        }
+    }
+}
+
 // Vote record
 #[derive(Debug, Clone)]
 pub struct VoteRecord {
@@ -87,6 +141,8 @@ pub struct DelegationRecord {
     pub balance: u128,             // Delegated balance
     pub timestamp: u64,            // Delegation timestamp
     pub is_active: bool,           // Active status
+    pub end_time: Option<u64>,     // Revocation timestamp
+    pub end_block: Option<u32>,    // Revocation block number
 }
 
 // Batch voting record
@@ -102,7 +158,7 @@ pub struct BatchVoteRecord {
 
 // Referenda participation metrics
 #[derive(Debug, Clone)]
-pub struct ReferendaParticipationMetrics {
+pub struct ReferendaParticipationMetrics<C = DefaultClock> {
     pub account_id: u32,                           // Account ID
     pub votes: Vec<VoteRecord>,                    // Vote records
     pub proposals: Vec<ProposalRecord>,            // Proposal records
@@ -118,14 +174,20 @@ pub struct ReferendaParticipationMetrics {
     pub conviction_usage: HashMap<Conviction, u32>, // Conviction usage count
     pub is_delegating: bool,                       // Is delegating votes?
     pub last_activity_time: u64,                   // Last activity timestamp
+    pub clock: C,                                  // Time source
 }
 
-impl ReferendaParticipationMetrics {
+#[cfg(feature = "std")]
+impl ReferendaParticipationMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> ReferendaParticipationMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         ReferendaParticipationMetrics {
             account_id,
@@ -143,16 +205,14 @@ impl ReferendaParticipationMetrics {
             conviction_usage: HashMap::new(),
             is_delegating: false,
             last_activity_time: now,
+            clock,
         }
     }
 
     // 1. Voting (vote)
     pub fn cast_vote(&mut self, referendum_id: u32, track: GovernanceTrack, vote_type: VoteType, 
                      conviction: Conviction, balance: u128, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let vote = VoteRecord {
             referendum_id,
@@ -193,10 +253,7 @@ impl ReferendaParticipationMetrics {
     // 2. Proposal submission (propose)
     pub fn submit_proposal(&mut self, proposal_id: u32, track: GovernanceTrack, 
                           preimage_hash: Option<String>, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let proposal = ProposalRecord {
             proposal_id,
@@ -223,10 +280,7 @@ impl ReferendaParticipationMetrics {
 
     // 3. Preimage submission (submitPreimage)
     pub fn submit_preimage(&mut self, hash: String, data: Vec<u8>, deposit: u128, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let preimage = PreimageRecord {
             hash,
@@ -248,10 +302,7 @@ impl ReferendaParticipationMetrics {
 
     // 4. Seconding support (seconding)
     pub fn second_proposal(&mut self, proposal_id: u32, deposit: u128, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let seconding = SecondingRecord {
             proposal_id,
@@ -270,16 +321,53 @@ impl ReferendaParticipationMetrics {
         &self.secondings
     }
 
+    // Total deposits locked in preimages (saturating)
+    pub fn total_preimage_deposits(&self) -> u128 {
+        self.preimages
+            .iter()
+            .fold(0u128, |total, p| total.saturating_add(p.deposit))
+    }
+
+    // Total deposits locked in secondings (saturating)
+    pub fn total_seconding_deposits(&self) -> u128 {
+        self.secondings
+            .iter()
+            .fold(0u128, |total, s| total.saturating_add(s.deposit))
+    }
+
+    // Total capital committed across preimages and secondings
+    pub fn total_locked_deposits(&self) -> u128 {
+        self.total_preimage_deposits()
+            .saturating_add(self.total_seconding_deposits())
+    }
+
     // 5. Track diversity (participation in different governance areas)
     pub fn get_track_diversity(&self) -> usize {
         self.track_participation.len()
     }
 
+    // Weighted track diversity (sum of weights of tracks participated in)
+    pub fn weighted_track_diversity(&self, weights: &TrackWeights) -> f64 {
+        self.track_participation
+            .keys()
+            .map(|track| weights.weight_for(track))
+            .sum()
+    }
+
     // Get track participation details
     pub fn get_track_participation(&self) -> &HashMap<GovernanceTrack, u32> {
         &self.track_participation
     }
 
+    // Track participation ordered by track, for stable output
+    pub fn get_track_participation_sorted(&self) -> Vec<(GovernanceTrack, u32)> {
+        let mut tracks: Vec<(GovernanceTrack, u32)> = self.track_participation.iter()
+            .map(|(track, count)| (track.clone(), *count))
+            .collect();
+        tracks.sort();
+        tracks
+    }
+
     // 6. Vote count (how many referendums voted on)
     pub fn get_total_votes_count(&self) -> u32 {
         self.total_votes
@@ -301,11 +389,38 @@ impl ReferendaParticipationMetrics {
         (self.aye_votes, self.nay_votes, self.abstain_votes)
     }
 
+    // Votes cast on a single track, including batch votes, in casting order
+    pub fn votes_for_track(&self, track: &GovernanceTrack) -> Vec<&VoteRecord> {
+        self.votes.iter().filter(|vote| &vote.track == track).collect()
+    }
+
+    // (aye, nay, abstain) counts for a single track
+    pub fn tally_for_track(&self, track: &GovernanceTrack) -> (u32, u32, u32) {
+        let mut tally = (0, 0, 0);
+        for vote in self.votes_for_track(track) {
+            match vote.vote_type {
+                VoteType::Aye => tally.0 += 1,
+                VoteType::Nay => tally.1 += 1,
+                VoteType::Abstain => tally.2 += 1,
+            }
+        }
+        tally
+    }
+
     // Get conviction usage
     pub fn get_conviction_usage(&self) -> &HashMap<Conviction, u32> {
         &self.conviction_usage
     }
 
+    // Conviction usage ordered from no conviction to 6x, for stable output
+    pub fn get_conviction_usage_sorted(&self) -> Vec<(Conviction, u32)> {
+        let mut usage: Vec<(Conviction, u32)> = self.conviction_usage.iter()
+            .map(|(conviction, count)| (conviction.clone(), *count))
+            .collect();
+        usage.sort();
+        usage
+    }
+
     // 8. Vote timing (regular, up-to-date)
     pub fn get_voting_frequency(&self) -> f64 {
         if self.votes.is_empty() {
@@ -314,7 +429,7 @@ impl ReferendaParticipationMetrics {
         
         let time_span = if let Some(first_vote) = self.votes.first() {
             let latest_vote = self.votes.last().unwrap();
-            (latest_vote.timestamp - first_vote.timestamp) as f64
+            latest_vote.timestamp.saturating_sub(first_vote.timestamp) as f64
         } else {
             0.0
         };
@@ -332,13 +447,10 @@ impl ReferendaParticipationMetrics {
             return false;
         }
         
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let now = self.clock.now_secs();
             
         if let Some(last_vote) = self.votes.last() {
-            let days_since_last_vote = (now - last_vote.timestamp) / (24 * 60 * 60);
+            let days_since_last_vote = now.saturating_sub(last_vote.timestamp) / (24 * 60 * 60);
             days_since_last_vote <= 30 // Voted within last 30 days
         } else {
             false
@@ -353,10 +465,7 @@ impl ReferendaParticipationMetrics {
     // Set delegation
     pub fn set_delegation(&mut self, delegatee: u32, track: GovernanceTrack, 
                          conviction: Conviction, balance: u128, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let delegation = DelegationRecord {
             delegator: self.account_id,
@@ -366,6 +475,8 @@ impl ReferendaParticipationMetrics {
             balance,
             timestamp,
             is_active: true,
+            end_time: None,
+            end_block: None,
         };
         
         self.delegations.push(delegation);
@@ -378,6 +489,28 @@ impl ReferendaParticipationMetrics {
         self.last_activity_time = timestamp;
     }
 
+    // Revoke active delegations to the delegatee on the track; returns
+    // whether any delegation was revoked
+    pub fn revoke_delegation(&mut self, delegatee: u32, track: &GovernanceTrack, block_number: u32) -> bool {
+        let timestamp = self.clock.now_secs();
+        let mut revoked = false;
+
+        for delegation in self.delegations.iter_mut()
+            .filter(|d| d.is_active && d.delegatee == delegatee && d.track == *track) {
+            delegation.is_active = false;
+            delegation.end_time = Some(timestamp);
+            delegation.end_block = Some(block_number);
+            revoked = true;
+        }
+
+        if revoked {
+            self.is_delegating = self.delegations.iter().any(|d| d.is_active);
+            self.last_activity_time = timestamp;
+        }
+
+        revoked
+    }
+
     // Get delegation records
     pub fn get_delegations(&self) -> &Vec<DelegationRecord> {
         &self.delegations
@@ -385,10 +518,7 @@ impl ReferendaParticipationMetrics {
 
     // 10. Batch voting behavior (bulk voting)
     pub fn cast_batch_votes(&mut self, batch_id: u32, votes: Vec<VoteRecord>, block_number: u32) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let total_weight: u128 = votes.iter().map(|v| v.balance).sum();
         
@@ -401,8 +531,6 @@ impl ReferendaParticipationMetrics {
             block_number,
         };
         
-        self.batch_votes.push(batch_vote);
-        self.votes.extend(votes);
         self.total_votes += batch_vote.votes.len() as u32;
         
         // Update vote type counts
@@ -422,6 +550,8 @@ impl ReferendaParticipationMetrics {
             *conviction_count += 1;
         }
         
+        self.batch_votes.push(batch_vote);
+        self.votes.extend(votes);
         self.last_activity_time = timestamp;
     }
 
@@ -472,18 +602,19 @@ impl ReferendaParticipationMetrics {
 
     // Get recent activity count (last 90 days)
     pub fn get_recent_activity_count(&self) -> u32 {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        let ninety_days_ago = now - (90 * 24 * 60 * 60);
+        self.get_recent_activity_count_within(RECENT_ACTIVITY_WINDOW_SECS)
+    }
+
+    // Get count of activities within the given window (seconds) before now
+    pub fn get_recent_activity_count_within(&self, window_secs: u64) -> u32 {
+        let cutoff = self.clock.now_secs().saturating_sub(window_secs);
         
         let mut count = 0;
-        count += self.votes.iter().filter(|v| v.timestamp > ninety_days_ago).count();
-        count += self.proposals.iter().filter(|p| p.timestamp > ninety_days_ago).count();
-        count += self.preimages.iter().filter(|p| p.timestamp > ninety_days_ago).count();
-        count += self.secondings.iter().filter(|s| s.timestamp > ninety_days_ago).count();
-        count += self.batch_votes.iter().filter(|b| b.timestamp > ninety_days_ago).count();
+        count += self.votes.iter().filter(|v| v.timestamp > cutoff).count();
+        count += self.proposals.iter().filter(|p| p.timestamp > cutoff).count();
+        count += self.preimages.iter().filter(|p| p.timestamp > cutoff).count();
+        count += self.secondings.iter().filter(|s| s.timestamp > cutoff).count();
+        count += self.batch_votes.iter().filter(|b| b.timestamp > cutoff).count();
         
         count as u32
     }
@@ -495,35 +626,212 @@ impl ReferendaParticipationMetrics {
 }
 
 // Referenda participation manager
-pub struct ReferendaParticipationManager {
-    pub metrics: HashMap<u32, ReferendaParticipationMetrics>, // Account ID -> Metrics
+pub struct ReferendaParticipationManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, ReferendaParticipationMetrics<C>>, // Account ID -> Metrics
+    pub clock: C,                                                // Time source for new metrics
 }
 
-impl ReferendaParticipationManager {
+#[cfg(feature = "std")]
+impl ReferendaParticipationManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> ReferendaParticipationManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         ReferendaParticipationManager {
             metrics: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &ReferendaParticipationMetrics {
-        let metrics = ReferendaParticipationMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &ReferendaParticipationMetrics<C> {
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| ReferendaParticipationMetrics::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&ReferendaParticipationMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&ReferendaParticipationMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, ReferendaParticipationMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, ReferendaParticipationMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: ReferendaParticipationMetrics<C>) -> Option<ReferendaParticipationMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<ReferendaParticipationMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
+
+    // Top n accounts by participation score (ties: ascending account ID)
+    pub fn top_accounts_by_score(&self, n: usize) -> Vec<(u32, f64)> {
+        let mut entries: Vec<(u32, f64)> = self.metrics
+            .iter()
+            .map(|(&account_id, metrics)| (account_id, metrics.get_participation_score()))
+            .collect();
+        
+        entries.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        entries.truncate(n);
+        entries
+    }
+}
+
+// Build delegation network: delegator -> delegatees (active delegations only)
+pub fn build_delegation_graph<C>(metrics: &[ReferendaParticipationMetrics<C>]) -> HashMap<u32, Vec<u32>> {
+    let mut graph: HashMap<u32, Vec<u32>> = HashMap::new();
+    
+    for account in metrics {
+        for delegation in account.delegations.iter().filter(|d| d.is_active) {
+            let delegatees = graph.entry(delegation.delegator).or_default();
+            if !delegatees.contains(&delegation.delegatee) {
+                delegatees.push(delegation.delegatee);
+            }
+        }
+    }
+    
+    for delegatees in graph.values_mut() {
+        delegatees.sort();
+    }
+    
+    graph
+}
+
+// Check whether the delegation network contains a cycle (e.g. A -> B -> A)
+pub fn has_delegation_cycle(graph: &HashMap<u32, Vec<u32>>) -> bool {
+    // 1 = on current path, 2 = fully explored
+    let mut state: HashMap<u32, u8> = HashMap::new();
+    
+    for &start in graph.keys() {
+        if state.contains_key(&start) {
+            continue;
+        }
+        
+        // Iterative DFS: (account, index of next delegatee to visit)
+        let mut stack = vec![(start, 0usize)];
+        state.insert(start, 1);
+        
+        while let Some(&mut (account, ref mut next)) = stack.last_mut() {
+            let delegatees = graph.get(&account).map(|d| d.as_slice()).unwrap_or(&[]);
+            
+            if *next < delegatees.len() {
+                let delegatee = delegatees[*next];
+                *next += 1;
+                
+                match state.get(&delegatee) {
+                    Some(1) => return true,
+                    Some(_) => {}
+                    None => {
+                        state.insert(delegatee, 1);
+                        stack.push((delegatee, 0));
+                    }
+                }
+            } else {
+                state.insert(account, 2);
+                stack.pop();
+            }
+        }
+    }
+    
+    false
+}
+
+// Upper bound on accounts compared by detect_coordinated_voting; beyond
+// this only the first MAX_COORDINATION_ACCOUNTS entries are checked
+pub const MAX_COORDINATION_ACCOUNTS: usize = 1000;
+
+// Find account pairs that vote the same way on the same referenda within
+// window_secs of each other. Score = matching referenda / referenda voted
+// on by the busier account of the pair (0.0-1.0), so two accounts that
+// share one vote out of many do not look coordinated. Only each account's
+// latest vote per referendum counts.
+//
+// Cost is O(n^2 * r) for n accounts with r referenda each; n is capped at
+// MAX_COORDINATION_ACCOUNTS. Result: (lower ID, higher ID, score), sorted
+// by score descending, pairs with no matches omitted.
+pub fn detect_coordinated_voting<C>(metrics: &[ReferendaParticipationMetrics<C>], window_secs: u64) -> Vec<(u32, u32, f64)> {
+    let accounts: Vec<_> = metrics
+        .iter()
+        .take(MAX_COORDINATION_ACCOUNTS)
+        .map(|m| {
+            let latest: HashMap<u32, (&VoteType, u64)> = m.votes
+                .iter()
+                .map(|v| (v.referendum_id, (&v.vote_type, v.timestamp)))
+                .collect();
+            (m.account_id, latest)
+        })
+        .collect();
+    
+    let mut pairs = Vec::new();
+    for (i, (account_a, votes_a)) in accounts.iter().enumerate() {
+        for (account_b, votes_b) in accounts.iter().skip(i + 1) {
+            if account_a == account_b {
+                continue;
+            }
+            
+            let busier = votes_a.len().max(votes_b.len());
+            if busier == 0 {
+                continue;
+            }
+            
+            let matches = votes_a
+                .iter()
+                .filter(|(referendum_id, (vote_a, time_a))| {
+                    votes_b.get(referendum_id).is_some_and(|(vote_b, time_b)| {
+                        vote_a == vote_b && time_a.abs_diff(*time_b) <= window_secs
+                    })
+                })
+                .count();
+            
+            if matches > 0 {
+                let pair = (*account_a.min(account_b), *account_a.max(account_b));
+                pairs.push((pair.0, pair.1, matches as f64 / busier as f64));
+            }
+        }
+    }
+    
+    pairs.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| (a.0, a.1).cmp(&(b.0, b.1))));
+    pairs
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut manager = ReferendaParticipationManager::with_clock(FixedClock(1699430400));
+        manager.create_metrics(1);
+        
+        let metrics = manager.metrics.get_mut(&1).unwrap();
+        metrics.clock = FixedClock(1699430460);
+        metrics.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        assert_eq!(metrics.get_votes()[0].timestamp, 1699430460);
+        assert_eq!(metrics.last_activity_time, 1699430460);
+    }
+
+    #[test]
+    fn test_clock_moved_backwards() {
+        let mut metrics = ReferendaParticipationMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        
+        // The next vote is stamped earlier than the first one
+        metrics.clock = FixedClock(1699430400 - 3600);
+        assert!(metrics.is_voting_regular());
+        metrics.cast_vote(2, GovernanceTrack::Root, VoteType::Nay, Conviction::None, 100, 1001);
+        assert_eq!(metrics.get_voting_frequency(), 2.0);
+    }
+
+    crate::manager_tests!(ReferendaParticipationManager, ReferendaParticipationMetrics, total_votes);
 
     #[test]
     fn test_referenda_metrics_creation() {
@@ -586,6 +894,33 @@ mod tests {
         assert_eq!(metrics.get_secondings().len(), 2);
     }
 
+    #[test]
+    fn test_locked_deposits() {
+        let mut metrics = ReferendaParticipationMetrics::new(1);
+        assert_eq!(metrics.total_locked_deposits(), 0);
+        
+        metrics.submit_preimage("0x01".to_string(), vec![1], 1000, 1000);
+        metrics.submit_preimage("0x02".to_string(), vec![2], 500, 1001);
+        metrics.second_proposal(1, 250, 1002);
+        
+        assert_eq!(metrics.total_preimage_deposits(), 1500);
+        assert_eq!(metrics.total_seconding_deposits(), 250);
+        assert_eq!(metrics.total_locked_deposits(), 1750);
+    }
+
+    #[test]
+    fn test_locked_deposits_overflow() {
+        let mut metrics = ReferendaParticipationMetrics::new(1);
+        
+        metrics.submit_preimage("0x01".to_string(), vec![1], u128::MAX, 1000);
+        metrics.submit_preimage("0x02".to_string(), vec![2], u128::MAX, 1001);
+        assert_eq!(metrics.total_preimage_deposits(), u128::MAX);
+        
+        metrics.second_proposal(1, u128::MAX, 1002);
+        assert_eq!(metrics.total_seconding_deposits(), u128::MAX);
+        assert_eq!(metrics.total_locked_deposits(), u128::MAX);
+    }
+
     #[test]
     fn test_track_diversity() {
         let mut manager = ReferendaParticipationManager::new();
@@ -601,6 +936,41 @@ mod tests {
         assert_eq!(*participation.get(&GovernanceTrack::Root).unwrap(), 1);
         assert_eq!(*participation.get(&GovernanceTrack::Treasury).unwrap(), 1);
         assert_eq!(*participation.get(&GovernanceTrack::Staking).unwrap(), 1);
+
+        assert_eq!(metrics.get_track_participation_sorted(), vec![
+            (GovernanceTrack::Root, 1),
+            (GovernanceTrack::Treasury, 1),
+            (GovernanceTrack::Staking, 1),
+        ]);
+        assert_eq!(metrics.get_conviction_usage_sorted(), vec![
+            (Conviction::Locked1x, 1),
+            (Conviction::Locked2x, 1),
+            (Conviction::Locked3x, 1),
+        ]);
+    }
+
+    #[test]
+    fn test_weighted_track_diversity() {
+        let weights = TrackWeights::default();
+        
+        let mut root_voter = ReferendaParticipationMetrics::new(1);
+        root_voter.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::Locked1x, 1000, 1000);
+        root_voter.cast_vote(2, GovernanceTrack::Root, VoteType::Nay, Conviction::Locked1x, 1000, 1001);
+        
+        let mut custom_voter = ReferendaParticipationMetrics::new(2);
+        custom_voter.cast_vote(1, GovernanceTrack::Custom("Ecosystem".to_string()), VoteType::Aye, Conviction::Locked1x, 1000, 1000);
+        
+        // Same unweighted diversity, but Root counts more
+        assert_eq!(root_voter.get_track_diversity(), custom_voter.get_track_diversity());
+        assert_eq!(root_voter.weighted_track_diversity(&weights), 3.0);
+        assert_eq!(custom_voter.weighted_track_diversity(&weights), weights.default_weight);
+        assert!(root_voter.weighted_track_diversity(&weights) > custom_voter.weighted_track_diversity(&weights));
+        
+        // Distinct custom tracks each contribute the default weight
+        custom_voter.cast_vote(2, GovernanceTrack::Custom("Bounties".to_string()), VoteType::Aye, Conviction::Locked1x, 1000, 1001);
+        assert_eq!(custom_voter.weighted_track_diversity(&weights), 2.0 * weights.default_weight);
+        
+        assert_eq!(ReferendaParticipationMetrics::new(3).weighted_track_diversity(&weights), 0.0);
     }
 
     #[test]
@@ -624,6 +994,26 @@ mod tests {
         assert_eq!(*conviction_usage.get(&Conviction::None).unwrap(), 1);
     }
 
+    #[test]
+    fn test_votes_for_track() {
+        let mut metrics = ReferendaParticipationMetrics::with_clock(1, FixedClock(1699430400));
+
+        metrics.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        metrics.cast_vote(2, GovernanceTrack::Treasury, VoteType::Nay, Conviction::Locked1x, 200, 1001);
+        metrics.cast_vote(3, GovernanceTrack::Treasury, VoteType::Aye, Conviction::None, 300, 1002);
+        metrics.cast_vote(4, GovernanceTrack::Root, VoteType::Abstain, Conviction::None, 400, 1003);
+        metrics.cast_vote(5, GovernanceTrack::Treasury, VoteType::Nay, Conviction::None, 500, 1004);
+
+        let treasury = metrics.votes_for_track(&GovernanceTrack::Treasury);
+        let referendum_ids: Vec<u32> = treasury.iter().map(|vote| vote.referendum_id).collect();
+        assert_eq!(referendum_ids, vec![2, 3, 5]);
+
+        assert_eq!(metrics.tally_for_track(&GovernanceTrack::Treasury), (1, 2, 0));
+        assert_eq!(metrics.tally_for_track(&GovernanceTrack::Root), (1, 0, 1));
+        assert!(metrics.votes_for_track(&GovernanceTrack::Staking).is_empty());
+        assert_eq!(metrics.tally_for_track(&GovernanceTrack::Staking), (0, 0, 0));
+    }
+
     #[test]
     fn test_delegation() {
         let mut manager = ReferendaParticipationManager::new();
@@ -638,6 +1028,93 @@ mod tests {
         assert_eq!(metrics.get_delegations().len(), 1);
     }
 
+    #[test]
+    fn test_revoke_delegation() {
+        let mut metrics = ReferendaParticipationMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.set_delegation(100, GovernanceTrack::Root, Conviction::Locked1x, 1000, 1000);
+        metrics.set_delegation(200, GovernanceTrack::Treasury, Conviction::Locked2x, 500, 1001);
+
+        metrics.clock = FixedClock(1699430500);
+        // No active delegation to 100 on the Treasury track
+        assert!(!metrics.revoke_delegation(100, &GovernanceTrack::Treasury, 1002));
+
+        assert!(metrics.revoke_delegation(100, &GovernanceTrack::Root, 1002));
+        assert!(metrics.is_delegating_votes());
+        let revoked = &metrics.get_delegations()[0];
+        assert!(!revoked.is_active);
+        assert_eq!(revoked.end_time, Some(1699430500));
+        assert_eq!(revoked.end_block, Some(1002));
+
+        assert!(metrics.revoke_delegation(200, &GovernanceTrack::Treasury, 1003));
+        assert!(!metrics.is_delegating_votes());
+        assert!(!metrics.revoke_delegation(200, &GovernanceTrack::Treasury, 1004));
+    }
+
+    #[test]
+    fn test_delegation_graph() {
+        let mut alice = ReferendaParticipationMetrics::new(1);
+        alice.set_delegation(2, GovernanceTrack::Root, Conviction::Locked1x, 1000, 1000);
+        alice.set_delegation(2, GovernanceTrack::Treasury, Conviction::Locked1x, 1000, 1001);
+        alice.set_delegation(3, GovernanceTrack::Staking, Conviction::Locked1x, 1000, 1002);
+        
+        let mut bob = ReferendaParticipationMetrics::new(2);
+        bob.set_delegation(3, GovernanceTrack::Root, Conviction::Locked2x, 500, 1003);
+        
+        let mut carol = ReferendaParticipationMetrics::new(3);
+        carol.set_delegation(4, GovernanceTrack::Root, Conviction::None, 100, 1004);
+        carol.delegations[0].is_active = false;
+        
+        let graph = build_delegation_graph(&[alice.clone(), bob.clone(), carol.clone()]);
+        assert_eq!(graph.get(&1), Some(&vec![2, 3]));
+        assert_eq!(graph.get(&2), Some(&vec![3]));
+        assert!(graph.get(&3).is_none()); // Inactive delegation is ignored
+        assert!(!has_delegation_cycle(&graph));
+        
+        // Carol delegates back to Alice: 1 -> 3 -> 1
+        carol.set_delegation(1, GovernanceTrack::Root, Conviction::None, 100, 1005);
+        let graph = build_delegation_graph(&[alice, bob, carol]);
+        assert!(has_delegation_cycle(&graph));
+        
+        assert!(build_delegation_graph::<DefaultClock>(&[]).is_empty());
+        assert!(!has_delegation_cycle(&HashMap::new()));
+    }
+
+    #[test]
+    fn test_delegation_self_cycle() {
+        let mut graph = HashMap::new();
+        graph.insert(7, vec![7]);
+        assert!(has_delegation_cycle(&graph));
+    }
+
+    #[test]
+    fn test_detect_coordinated_voting() {
+        let mut alice = ReferendaParticipationMetrics::with_clock(1, FixedClock(1000));
+        let mut bob = ReferendaParticipationMetrics::with_clock(2, FixedClock(1010));
+        let mut carol = ReferendaParticipationMetrics::with_clock(3, FixedClock(5000));
+        
+        for referendum_id in 1..=4 {
+            alice.cast_vote(referendum_id, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+            carol.cast_vote(referendum_id, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1400);
+        }
+        // Bob mirrors Alice 10 seconds later on three of her four referenda
+        for referendum_id in 1..=3 {
+            bob.cast_vote(referendum_id, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1001);
+        }
+        bob.cast_vote(4, GovernanceTrack::Root, VoteType::Nay, Conviction::None, 100, 1001);
+        
+        let pairs = detect_coordinated_voting(&[carol, bob, alice], 60);
+        assert_eq!(pairs, vec![(1, 2, 0.75)]);
+        
+        // A wider window also catches Carol, who voted the same way later
+        let mut alice = ReferendaParticipationMetrics::with_clock(1, FixedClock(1000));
+        let mut carol = ReferendaParticipationMetrics::with_clock(3, FixedClock(5000));
+        alice.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        carol.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1400);
+        assert_eq!(detect_coordinated_voting(&[alice, carol], 4000), vec![(1, 3, 1.0)]);
+        
+        assert!(detect_coordinated_voting::<DefaultClock>(&[], 60).is_empty());
+    }
+
     #[test]
     fn test_batch_voting() {
         let mut manager = ReferendaParticipationManager::new();
@@ -672,6 +1149,23 @@ mod tests {
         assert_eq!(metrics.get_batch_votes().len(), 1);
     }
 
+    #[test]
+    fn test_top_accounts_by_score() {
+        let mut manager = ReferendaParticipationManager::new();
+        for account_id in [5, 4, 3] {
+            manager.create_metrics(account_id);
+        }
+        
+        let metrics = manager.metrics.get_mut(&3).unwrap();
+        metrics.submit_proposal(1, GovernanceTrack::Treasury, None, 1000);
+        
+        let top = manager.top_accounts_by_score(3);
+        assert_eq!(top, vec![(3, 9.0), (4, 0.0), (5, 0.0)]);
+        
+        assert_eq!(manager.top_accounts_by_score(1), vec![(3, 9.0)]);
+        assert_eq!(manager.top_accounts_by_score(10).len(), 3);
+    }
+
     #[test]
     fn test_participation_score() {
         let mut manager = ReferendaParticipationManager::new();
@@ -701,4 +1195,19 @@ mod tests {
         let is_regular = metrics.is_voting_regular();
         assert!(is_regular == true || is_regular == false);
     }
+
+    #[test]
+    fn test_recent_activity_window() {
+        let now = 1699430400;
+        let mut metrics = ReferendaParticipationMetrics::with_clock(1, FixedClock(now - 200 * 24 * 60 * 60));
+        metrics.cast_vote(1, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        metrics.clock = FixedClock(now - 20 * 24 * 60 * 60);
+        metrics.cast_vote(2, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        metrics.clock = FixedClock(now);
+
+        assert_eq!(metrics.get_recent_activity_count(), 1);
+        assert_eq!(metrics.get_recent_activity_count_within(7 * 24 * 60 * 60), 0);
+        assert_eq!(metrics.get_recent_activity_count_within(30 * 24 * 60 * 60), 1);
+        assert_eq!(metrics.get_recent_activity_count_within(365 * 24 * 60 * 60), 2);
+    }
 }
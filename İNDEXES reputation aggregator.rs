@@ -0,0 +1,276 @@
+use crate::clock::{Clock, DefaultClock};
+use crate::extrinsic_activity::ExtrinsicActivityManager;
+use crate::extrinsic_activity::ExtrinsicActivityMetrics;
+use crate::off_chain::OffChainTrustManager;
+use crate::on_chain::SocialTrustMetrics;
+use crate::referenda_participation::{ReferendaParticipationManager, ReferendaParticipationMetrics};
+use crate::staking_events::{StakingActivityMetrics, StakingMetricsManager};
+
+// Raw domain scores at which a domain counts as fully saturated
+pub const STAKING_SCORE_CAP: f64 = 50.0;
+pub const GOVERNANCE_SCORE_CAP: f64 = 100.0;
+pub const EXTRINSIC_SCORE_CAP: f64 = 60.0;
+pub const OFF_CHAIN_SCORE_CAP: f64 = 100.0;
+
+// Common interface for per-account activity scorers
+pub trait Scorable {
+    // Raw score on the scorer's own scale
+    fn score(&self) -> f64;
+    // Name of the activity domain the score describes
+    fn domain(&self) -> &'static str;
+}
+
+impl<C: Clock> Scorable for SocialTrustMetrics<C> {
+    fn score(&self) -> f64 {
+        self.get_trust_score()
+    }
+
+    fn domain(&self) -> &'static str {
+        "social_trust"
+    }
+}
+
+impl<C: Clock> Scorable for ReferendaParticipationMetrics<C> {
+    fn score(&self) -> f64 {
+        self.get_participation_score()
+    }
+
+    fn domain(&self) -> &'static str {
+        "governance"
+    }
+}
+
+impl<C: Clock> Scorable for ExtrinsicActivityMetrics<C> {
+    fn score(&self) -> f64 {
+        self.get_activity_score()
+    }
+
+    fn domain(&self) -> &'static str {
+        "extrinsics"
+    }
+}
+
+impl<C: Clock> Scorable for StakingActivityMetrics<C> {
+    fn score(&self) -> f64 {
+        self.get_overall_staking_score()
+    }
+
+    fn domain(&self) -> &'static str {
+        "staking"
+    }
+}
+
+// Weighted mean of raw scores, paired with weights by position.
+// Items without a weight (or weights without an item) are ignored, negative
+// weights count as 0, non-finite scores count as 0, and if every weight is 0
+// the result is 0.
+pub fn aggregate(items: &[&dyn Scorable], weights: &[f64]) -> f64 {
+    let (total_weight, weighted) = items.iter().zip(weights)
+        .fold((0.0, 0.0), |(total, sum), (item, &weight)| {
+            let weight = weight.max(0.0);
+            let score = item.score();
+            let score = if score.is_finite() { score } else { 0.0 };
+            (total + weight, sum + weight * score)
+        });
+
+    if total_weight <= 0.0 {
+        return 0.0;
+    }
+
+    weighted / total_weight
+}
+
+// Relative weight of each domain in the aggregate score
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DomainWeights {
+    pub staking: f64,     // StakingMetricsManager
+    pub governance: f64,  // ReferendaParticipationManager
+    pub extrinsics: f64,  // ExtrinsicActivityManager
+    pub off_chain: f64,   // OffChainTrustManager
+}
+
+impl Default for DomainWeights {
+    fn default() -> Self {
+        DomainWeights {
+            staking: 0.3,
+            governance: 0.3,
+            extrinsics: 0.2,
+            off_chain: 0.2,
+        }
+    }
+}
+
+// Per-domain scores for one account, each normalized to 0.0-1.0
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DomainScores {
+    pub staking: f64,
+    pub governance: f64,
+    pub extrinsics: f64,
+    pub off_chain: f64,
+}
+
+// Account-level trust score across all activity managers
+pub struct ReputationAggregator<'a, C = DefaultClock> {
+    pub staking: &'a StakingMetricsManager<C>,
+    pub governance: &'a ReferendaParticipationManager<C>,
+    pub extrinsics: &'a ExtrinsicActivityManager<C>,
+    pub off_chain: &'a OffChainTrustManager<C>,
+    pub weights: DomainWeights,
+}
+
+impl<'a, C: Clock + Clone> ReputationAggregator<'a, C> {
+    pub fn new(staking: &'a StakingMetricsManager<C>,
+               governance: &'a ReferendaParticipationManager<C>,
+               extrinsics: &'a ExtrinsicActivityManager<C>,
+               off_chain: &'a OffChainTrustManager<C>) -> Self {
+        ReputationAggregator {
+            staking,
+            governance,
+            extrinsics,
+            off_chain,
+            weights: DomainWeights::default(),
+        }
+    }
+
+    pub fn with_weights(mut self, weights: DomainWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    // Each domain's raw score divided by its cap and clamped to 0.0-1.0;
+    // a domain with no metrics for the account contributes 0.0
+    pub fn domain_scores(&self, account_id: u32) -> DomainScores {
+        DomainScores {
+            staking: normalize(self.staking.get_metrics(account_id)
+                .map(|m| m.get_overall_staking_score()), STAKING_SCORE_CAP),
+            governance: normalize(self.governance.get_metrics(account_id)
+                .map(|m| m.get_participation_score()), GOVERNANCE_SCORE_CAP),
+            extrinsics: normalize(self.extrinsics.get_metrics(account_id)
+                .map(|m| m.get_activity_score()), EXTRINSIC_SCORE_CAP),
+            off_chain: normalize(self.off_chain.get_metrics(account_id)
+                .map(|m| m.get_offchain_trust_score()), OFF_CHAIN_SCORE_CAP),
+        }
+    }
+
+    // Weighted mean of the normalized domain scores, scaled to 0-100.
+    // Weights are relative (they need not sum to 1); negative weights count
+    // as 0, and if every weight is 0 the score is 0.
+    pub fn aggregate_score(&self, account_id: u32) -> f64 {
+        let scores = self.domain_scores(account_id);
+        let weights = [
+            (self.weights.staking.max(0.0), scores.staking),
+            (self.weights.governance.max(0.0), scores.governance),
+            (self.weights.extrinsics.max(0.0), scores.extrinsics),
+            (self.weights.off_chain.max(0.0), scores.off_chain),
+        ];
+
+        let total_weight: f64 = weights.iter().map(|(w, _)| w).sum();
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+
+        let weighted: f64 = weights.iter().map(|(w, s)| w * s).sum();
+        (weighted / total_weight * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+fn normalize(raw: Option<f64>, cap: f64) -> f64 {
+    match raw {
+        Some(score) if score.is_finite() => (score / cap).clamp(0.0, 1.0),
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::extrinsic_activity::ExtrinsicType;
+    use crate::referenda_participation::{Conviction, GovernanceTrack, VoteType};
+
+    const NOW: u64 = 1699430400;
+
+    #[test]
+    fn test_aggregate_score_missing_account() {
+        let staking = StakingMetricsManager::with_clock(FixedClock(NOW));
+        let governance = ReferendaParticipationManager::with_clock(FixedClock(NOW));
+        let extrinsics = ExtrinsicActivityManager::with_clock(FixedClock(NOW));
+        let off_chain = OffChainTrustManager::with_clock(FixedClock(NOW));
+
+        let aggregator = ReputationAggregator::new(&staking, &governance, &extrinsics, &off_chain);
+        assert_eq!(aggregator.domain_scores(1), DomainScores::default());
+        assert_eq!(aggregator.aggregate_score(1), 0.0);
+    }
+
+    #[test]
+    fn test_aggregate_score_single_domain() {
+        let staking = StakingMetricsManager::with_clock(FixedClock(NOW));
+        let mut governance = ReferendaParticipationManager::with_clock(FixedClock(NOW));
+        let extrinsics = ExtrinsicActivityManager::with_clock(FixedClock(NOW));
+        let off_chain = OffChainTrustManager::with_clock(FixedClock(NOW));
+
+        governance.create_metrics(1);
+        let metrics = governance.metrics.get_mut(&1).unwrap();
+        for referendum_id in 0..3 {
+            metrics.cast_vote(referendum_id, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        }
+        // 3 votes * 2 + 1 track * 4 + 10 regular voting bonus = 20 of a 100 cap
+        assert_eq!(metrics.get_participation_score(), 20.0);
+
+        let aggregator = ReputationAggregator::new(&staking, &governance, &extrinsics, &off_chain);
+        assert!((aggregator.domain_scores(1).governance - 0.2).abs() < 1e-9);
+        // Governance carries 0.3 of the default weight
+        assert!((aggregator.aggregate_score(1) - 6.0).abs() < 1e-9);
+
+        let governance_only = DomainWeights { staking: 0.0, governance: 1.0, extrinsics: 0.0, off_chain: 0.0 };
+        let aggregator = aggregator.with_weights(governance_only);
+        assert!((aggregator.aggregate_score(1) - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aggregate_score_bounds() {
+        let staking = StakingMetricsManager::with_clock(FixedClock(NOW));
+        let governance = ReferendaParticipationManager::with_clock(FixedClock(NOW));
+        let mut extrinsics = ExtrinsicActivityManager::with_clock(FixedClock(NOW));
+        let off_chain = OffChainTrustManager::with_clock(FixedClock(NOW));
+
+        extrinsics.create_metrics(1);
+        let metrics = extrinsics.metrics.get_mut(&1).unwrap();
+        for block in 0..200 {
+            metrics.add_extrinsic("balances".to_string(), "transfer".to_string(), ExtrinsicType::Transfer,
+                                  block, true, 1000, 10);
+        }
+
+        let extrinsics_only = DomainWeights { staking: 0.0, governance: -1.0, extrinsics: 2.0, off_chain: 0.0 };
+        let aggregator = ReputationAggregator::new(&staking, &governance, &extrinsics, &off_chain)
+            .with_weights(extrinsics_only);
+        // Raw score is far above the cap, so the domain saturates at 100
+        assert_eq!(aggregator.aggregate_score(1), 100.0);
+
+        let no_weights = DomainWeights { staking: 0.0, governance: 0.0, extrinsics: 0.0, off_chain: 0.0 };
+        assert_eq!(aggregator.with_weights(no_weights).aggregate_score(1), 0.0);
+    }
+
+    #[test]
+    fn test_scorable_aggregate() {
+        let mut governance = ReferendaParticipationMetrics::with_clock(1, FixedClock(NOW));
+        for referendum_id in 0..3 {
+            governance.cast_vote(referendum_id, GovernanceTrack::Root, VoteType::Aye, Conviction::None, 100, 1000);
+        }
+        let social = SocialTrustMetrics::with_clock(1, FixedClock(NOW));
+        let extrinsics = ExtrinsicActivityMetrics::with_clock(1, FixedClock(NOW));
+        let staking = StakingActivityMetrics::with_clock(1, FixedClock(NOW));
+
+        let items: [&dyn Scorable; 4] = [&governance, &social, &extrinsics, &staking];
+        let domains: Vec<&str> = items.iter().map(|item| item.domain()).collect();
+        assert_eq!(domains, vec!["governance", "social_trust", "extrinsics", "staking"]);
+        assert_eq!(items[0].score(), 20.0);
+
+        // Governance 20.0 at weight 3 against social trust 0.0 at weight 1
+        assert!((aggregate(&items[..2], &[3.0, 1.0]) - 15.0).abs() < 1e-9);
+        // Unpaired items are ignored
+        assert!((aggregate(&items, &[1.0]) - 20.0).abs() < 1e-9);
+        assert_eq!(aggregate(&items, &[-1.0, 0.0]), 0.0);
+        assert_eq!(aggregate(&[], &[]), 0.0);
+    }
+}
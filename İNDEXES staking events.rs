@@ -1,5 +1,16 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec::Vec, string::String, collections::BTreeMap as HashMap};
+
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::clock::{Clock, DefaultClock};
+use crate::math::safe_ratio;
+#[cfg(feature = "std")]
+use crate::clock::SystemClock;
 
 // Staking operation types
 #[derive(Debug, Clone, PartialEq)]
@@ -34,9 +45,20 @@ pub struct ValidatorInfo {
     pub is_active: bool,                  // Active status
 }
 
+// Commission at or above which a validator earns no safety points
+pub const MAX_SAFE_COMMISSION: f64 = 0.2;
+
+// Points for validator quality and for spreading nominations; the safe
+// validator selection score is out of their sum (10)
+pub const VALIDATOR_QUALITY_POINTS: f64 = 8.0;
+pub const VALIDATOR_DIVERSIFICATION_POINTS: f64 = 2.0;
+
+// Number of safe nominated validators that earns the full diversification points
+pub const DIVERSIFICATION_TARGET: usize = 5;
+
 // Staking activity metrics
 #[derive(Debug, Clone)]
-pub struct StakingActivityMetrics {
+pub struct StakingActivityMetrics<C = DefaultClock> {
     pub account_id: u32,                             // Account ID
     pub staking_activities: Vec<StakingActivityRecord>, // All staking operations
     pub validators: HashMap<u32, ValidatorInfo>,      // Validator information
@@ -49,14 +71,20 @@ pub struct StakingActivityMetrics {
     pub slashing_events: u32,                         // Slashing events count
     pub total_staking_extrinsics: u32,                // Total staking extrinsics count
     pub last_activity_time: u64,                      // Last activity timestamp
+    pub clock: C,                                     // Time source
 }
 
-impl StakingActivityMetrics {
+#[cfg(feature = "std")]
+impl StakingActivityMetrics<SystemClock> {
     pub fn new(account_id: u32) -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        Self::with_clock(account_id, SystemClock)
+    }
+}
+
+impl<C: Clock> StakingActivityMetrics<C> {
+    // Metrics whose timestamps are read from the given clock
+    pub fn with_clock(account_id: u32, clock: C) -> Self {
+        let now = clock.now_secs();
             
         StakingActivityMetrics {
             account_id,
@@ -71,15 +99,13 @@ impl StakingActivityMetrics {
             slashing_events: 0,
             total_staking_extrinsics: 0,
             last_activity_time: now,
+            clock,
         }
     }
 
     // 1. Stake Initiation (Bonded)
     pub fn start_staking(&mut self, amount: u128, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         // Set first stake date
         if self.first_stake_date.is_none() {
@@ -113,11 +139,8 @@ impl StakingActivityMetrics {
     // Active duration calculation (in days)
     pub fn get_active_duration_days(&self) -> Option<u64> {
         if let Some(first_stake) = self.first_stake_date {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards")
-                .as_secs();
-            let duration_seconds = now - first_stake;
+            let now = self.clock.now_secs();
+            let duration_seconds = now.saturating_sub(first_stake);
             Some(duration_seconds / (24 * 60 * 60)) // Convert seconds to days
         } else {
             None
@@ -138,7 +161,7 @@ impl StakingActivityMetrics {
         if self.current_staked_amount == 0 {
             0.0
         } else {
-            (self.current_staked_amount as f64).ln() / 10.0 // Logarithmic scaling
+            ln(self.current_staked_amount as f64) / 10.0 // Logarithmic scaling
         }
     }
 
@@ -155,10 +178,7 @@ impl StakingActivityMetrics {
 
     // 3. Unbonding Initiation (Unbonded)
     pub fn start_unbonding(&mut self, amount: u128, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = StakingActivityRecord {
             operation_type: StakingOperation::Unbond,
@@ -176,6 +196,10 @@ impl StakingActivityMetrics {
         self.last_activity_time = timestamp;
     }
 
+    pub fn get_total_unbonded_amount(&self) -> u128 {
+        self.total_unbonded_amount
+    }
+
     pub fn is_unbonding_started(&self) -> bool {
         self.staking_activities.iter().any(|a| a.operation_type == StakingOperation::Unbond)
     }
@@ -187,20 +211,14 @@ impl StakingActivityMetrics {
         }
         
         let first_stake = self.first_stake_date.unwrap();
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let now = self.clock.now_secs();
             
-        Some(now - first_stake)
+        Some(now.saturating_sub(first_stake))
     }
 
     // 4. Rebonding / Restaking
     pub fn rebond_staking(&mut self, amount: u128, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = StakingActivityRecord {
             operation_type: StakingOperation::Rebond,
@@ -221,12 +239,22 @@ impl StakingActivityMetrics {
         self.staking_activities.iter().filter(|a| a.operation_type == StakingOperation::Rebond).count()
     }
 
+    pub fn get_total_rebonded_amount(&self) -> u128 {
+        self.staking_activities.iter()
+            .filter(|a| a.operation_type == StakingOperation::Rebond)
+            .filter_map(|a| a.amount)
+            .fold(0u128, |total, amount| total.saturating_add(amount))
+    }
+
+    // Share of unbonded stake that was re-committed, 0.0-1.0
+    // (0.0 when nothing was unbonded)
+    pub fn rebonding_ratio(&self) -> f64 {
+        safe_ratio(self.get_total_rebonded_amount() as f64, self.total_unbonded_amount as f64).min(1.0)
+    }
+
     // 5. Withdraw (Withdrawal)
     pub fn withdraw_stake(&mut self, amount: u128, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = StakingActivityRecord {
             operation_type: StakingOperation::Withdraw,
@@ -249,10 +277,7 @@ impl StakingActivityMetrics {
 
     // 6. Validator Change / Nominate
     pub fn nominate_validators(&mut self, validator_ids: Vec<u32>, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = StakingActivityRecord {
             operation_type: StakingOperation::Nominate,
@@ -313,11 +338,14 @@ impl StakingActivityMetrics {
         self.slashing_events
     }
 
+    // Slashed validator IDs in ascending order
     pub fn get_slashed_validators(&self) -> Vec<u32> {
-        self.validators.iter()
+        let mut slashed: Vec<u32> = self.validators.iter()
             .filter(|(_, v)| v.is_slashed)
             .map(|(id, _)| *id)
-            .collect()
+            .collect();
+        slashed.sort_unstable();
+        slashed
     }
 
     // Risky validator selection penalty score
@@ -327,10 +355,7 @@ impl StakingActivityMetrics {
 
     // 8. Reward Claim (Reward Withdrawal)
     pub fn claim_reward(&mut self, amount: u128, block_number: u32, extrinsic_hash: String) {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
+        let timestamp = self.clock.now_secs();
             
         let activity = StakingActivityRecord {
             operation_type: StakingOperation::ClaimReward,
@@ -351,17 +376,63 @@ impl StakingActivityMetrics {
         self.total_rewards_claimed
     }
 
-    // Regular reward claim analysis
-    pub fn get_reward_claim_frequency(&self) -> f64 {
+    // Time-weighted average bonded amount from the first stake until now,
+    // replaying Bond/Rebond (+) and Unbond (-) records in order
+    pub fn get_average_staked_amount(&self) -> Option<f64> {
+        let first_stake = self.first_stake_date?;
+        let now = self.clock.now_secs();
+        let total_seconds = now.saturating_sub(first_stake);
+        if total_seconds == 0 {
+            return None;
+        }
+
+        let mut staked: u128 = 0;
+        let mut since = first_stake;
+        let mut stake_seconds = 0.0;
+        for activity in &self.staking_activities {
+            let amount = match (&activity.operation_type, activity.amount) {
+                (StakingOperation::Bond, Some(amount))
+                | (StakingOperation::Rebond, Some(amount))
+                | (StakingOperation::Unbond, Some(amount)) => amount,
+                _ => continue,
+            };
+            let at = activity.timestamp.clamp(since, now);
+            stake_seconds += staked as f64 * (at - since) as f64;
+            since = at;
+            staked = if activity.operation_type == StakingOperation::Unbond {
+                staked.saturating_sub(amount)
+            } else {
+                staked.saturating_add(amount)
+            };
+        }
+        stake_seconds += staked as f64 * now.saturating_sub(since) as f64;
+
+        Some(stake_seconds / total_seconds as f64)
+    }
+
+    // Estimated annual reward rate (0.1 = 10% APR): claimed rewards divided by
+    // the time-weighted average stake, scaled from the active duration to a year.
+    // Simplifying assumptions: rewards are attributed when claimed rather than
+    // when earned, claimed rewards are not compounded, and the active duration
+    // is counted in whole days. None with less than a day of staking, no
+    // claimed rewards or no stake.
+    pub fn estimated_reward_rate(&self) -> Option<f64> {
         if self.total_rewards_claimed == 0 {
-            return 0.0;
+            return None;
         }
-        
+        let days = self.get_active_duration_days().filter(|&days| days > 0)?;
+        let average_stake = self.get_average_staked_amount().filter(|&stake| stake > 0.0)?;
+
+        Some(self.total_rewards_claimed as f64 / average_stake * 365.0 / days as f64)
+    }
+
+    // Regular reward claim analysis
+    pub fn get_reward_claim_frequency(&self) -> f64 {
         let reward_activities = self.staking_activities.iter()
             .filter(|a| a.operation_type == StakingOperation::ClaimReward)
             .count();
             
-        reward_activities as f64 / self.staking_activities.len() as f64
+        safe_ratio(reward_activities as f64, self.staking_activities.len() as f64)
     }
 
     // 9. Staking Extrinsic Count
@@ -420,57 +491,230 @@ impl StakingActivityMetrics {
         self.validators.insert(validator_id, validator);
     }
 
-    // Safe validator selection check
-    pub fn get_safe_validator_selection_score(&self) -> f64 {
-        let nominated_validators: Vec<u32> = self.get_nominated_validators();
-        if nominated_validators.is_empty() {
-            return 0.0;
+    // Safety of a single validator, 0.0-1.0: falls linearly from 1.0 at 0%
+    // commission to 0.0 at MAX_SAFE_COMMISSION; unknown, slashed or inactive
+    // validators are 0.0
+    pub fn get_validator_safety(&self, validator_id: u32) -> f64 {
+        match self.validators.get(&validator_id) {
+            Some(validator) if !validator.is_slashed && validator.is_active => {
+                (1.0 - validator.commission / MAX_SAFE_COMMISSION).clamp(0.0, 1.0)
+            }
+            _ => 0.0,
         }
-        
-        let safe_validators = nominated_validators.iter()
-            .filter(|&id| {
-                if let Some(validator) = self.validators.get(id) {
-                    !validator.is_slashed && validator.is_active && validator.commission < 0.2 // Less than 20% commission
-                } else {
-                    false
-                }
-            })
+    }
+
+    // Quality points each nominated validator contributes, by validator ID.
+    // Contributions sum to the quality part of the selection score.
+    pub fn get_validator_safety_contributions(&self) -> Vec<(u32, f64)> {
+        let nominated_validators = self.get_nominated_validators();
+        let share = VALIDATOR_QUALITY_POINTS / nominated_validators.len().max(1) as f64;
+
+        nominated_validators.into_iter()
+            .map(|id| (id, self.get_validator_safety(id) * share))
+            .collect()
+    }
+
+    // Diversification points: grows with the number of nominated validators
+    // that have any safety, up to DIVERSIFICATION_TARGET
+    pub fn get_validator_diversification_bonus(&self) -> f64 {
+        let safe_validators = self.get_nominated_validators().into_iter()
+            .filter(|&id| self.get_validator_safety(id) > 0.0)
             .count();
-            
-        (safe_validators as f64 / nominated_validators.len() as f64) * 10.0 // Max 10 points
+
+        safe_validators.min(DIVERSIFICATION_TARGET) as f64 / DIVERSIFICATION_TARGET as f64
+            * VALIDATOR_DIVERSIFICATION_POINTS
+    }
+
+    // Safe validator selection score (max 10 points): per-validator
+    // contributions plus the diversification bonus
+    pub fn get_safe_validator_selection_score(&self) -> f64 {
+        let quality: f64 = self.get_validator_safety_contributions().iter()
+            .map(|(_, contribution)| contribution)
+            .sum();
+
+        quality + self.get_validator_diversification_bonus()
     }
 }
 
+// Natural logarithm; core has no float math, so no_std builds use libm
+#[cfg(feature = "std")]
+fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
 // Staking metrics manager
-pub struct StakingMetricsManager {
-    pub metrics: HashMap<u32, StakingActivityMetrics>, // Account ID -> Metrics
+pub struct StakingMetricsManager<C = DefaultClock> {
+    pub metrics: HashMap<u32, StakingActivityMetrics<C>>, // Account ID -> Metrics
+    pub clock: C,                                         // Time source for new metrics
 }
 
-impl StakingMetricsManager {
+#[cfg(feature = "std")]
+impl StakingMetricsManager<SystemClock> {
     pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + Clone> StakingMetricsManager<C> {
+    // Manager whose new metrics read time from the given clock
+    pub fn with_clock(clock: C) -> Self {
         StakingMetricsManager {
             metrics: HashMap::new(),
+            clock,
         }
     }
 
-    pub fn create_metrics(&mut self, account_id: u32) -> &StakingActivityMetrics {
-        let metrics = StakingActivityMetrics::new(account_id);
-        self.metrics.insert(account_id, metrics);
-        self.metrics.get(&account_id).unwrap()
+    // Get or create metrics; an existing account's data is returned untouched
+    pub fn create_metrics(&mut self, account_id: u32) -> &StakingActivityMetrics<C> {
+        self.metrics
+            .entry(account_id)
+            .or_insert_with(|| StakingActivityMetrics::with_clock(account_id, self.clock.clone()))
     }
 
-    pub fn get_metrics(&self, account_id: u32) -> Option<&StakingActivityMetrics> {
+    pub fn get_metrics(&self, account_id: u32) -> Option<&StakingActivityMetrics<C>> {
         self.metrics.get(&account_id)
     }
 
-    pub fn get_all_metrics(&self) -> &HashMap<u32, StakingActivityMetrics> {
+    pub fn get_all_metrics(&self) -> &HashMap<u32, StakingActivityMetrics<C>> {
         &self.metrics
     }
+
+    // Insert or replace metrics, returning the previous entry if any
+    pub fn insert_metrics(&mut self, account_id: u32, metrics: StakingActivityMetrics<C>) -> Option<StakingActivityMetrics<C>> {
+        self.metrics.insert(account_id, metrics)
+    }
+
+    // Remove an account's metrics
+    pub fn remove_metrics(&mut self, account_id: u32) -> Option<StakingActivityMetrics<C>> {
+        self.metrics.remove(&account_id)
+    }
+
+    // Top n accounts by overall staking score (ties: ascending account ID)
+    pub fn top_accounts_by_score(&self, n: usize) -> Vec<(u32, f64)> {
+        let mut entries: Vec<(u32, f64)> = self.metrics
+            .iter()
+            .map(|(&account_id, metrics)| (account_id, metrics.get_overall_staking_score()))
+            .collect();
+        
+        entries.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        entries.truncate(n);
+        entries
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn test_fixed_clock_timestamps() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.start_staking(1000, 100, "0x1".to_string());
+        assert_eq!(metrics.get_first_stake_date(), Some(1699430400));
+        
+        // Three days later
+        metrics.clock = FixedClock(1699430400 + 3 * 24 * 60 * 60);
+        assert_eq!(metrics.get_active_duration_days(), Some(3));
+    }
+
+    #[test]
+    fn test_clock_moved_backwards() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.start_staking(1000, 100, "0x1".to_string());
+        
+        // A host clock behind the recorded stake reads as zero elapsed time
+        metrics.clock = FixedClock(1699430400 - 3600);
+        assert_eq!(metrics.get_active_duration_days(), Some(0));
+        assert_eq!(metrics.get_average_stake_duration(), Some(0));
+        assert_eq!(metrics.get_average_staked_amount(), None);
+    }
+
+    #[test]
+    fn test_estimated_reward_rate() {
+        const DAY: u64 = 24 * 60 * 60;
+        let start = 1699430400;
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(start));
+        assert_eq!(metrics.estimated_reward_rate(), None);
+
+        metrics.start_staking(1000, 100, "0x1".to_string());
+        // Half a year at 1000, then half a year at 500: average stake 750
+        metrics.clock = FixedClock(start + 182 * DAY);
+        metrics.start_unbonding(500, 200, "0x2".to_string());
+        metrics.clock = FixedClock(start + 364 * DAY);
+        assert_eq!(metrics.estimated_reward_rate(), None);
+
+        metrics.claim_reward(75, 300, "0x3".to_string());
+        assert_eq!(metrics.get_average_staked_amount(), Some(750.0));
+        let rate = metrics.estimated_reward_rate().unwrap();
+        assert!((rate - 75.0 / 750.0 * 365.0 / 364.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimated_reward_rate_short_duration() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.start_staking(1000, 100, "0x1".to_string());
+        metrics.claim_reward(10, 101, "0x2".to_string());
+
+        // Less than a full day staked
+        metrics.clock = FixedClock(1699430400 + 3600);
+        assert_eq!(metrics.estimated_reward_rate(), None);
+    }
+
+    #[test]
+    fn test_rebonding_ratio() {
+        let mut rebonder = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        assert_eq!(rebonder.rebonding_ratio(), 0.0);
+
+        rebonder.start_staking(1000, 100, "0x1".to_string());
+        rebonder.start_unbonding(600, 101, "0x2".to_string());
+        rebonder.rebond_staking(400, 102, "0x3".to_string());
+        rebonder.rebond_staking(200, 103, "0x4".to_string());
+        assert!((rebonder.rebonding_ratio() - 1.0).abs() < 1e-9);
+        assert_eq!(rebonder.get_current_staked_amount(), 1000);
+
+        let mut withdrawer = StakingActivityMetrics::with_clock(2, FixedClock(1699430400));
+        withdrawer.start_staking(1000, 100, "0x1".to_string());
+        withdrawer.start_unbonding(1000, 101, "0x2".to_string());
+        withdrawer.withdraw_stake(1000, 102, "0x3".to_string());
+        assert_eq!(withdrawer.rebonding_ratio(), 0.0);
+
+        // Partial rebond
+        withdrawer.rebond_staking(250, 103, "0x4".to_string());
+        assert!((withdrawer.rebonding_ratio() - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_total_rebonded_amount_saturates() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        metrics.rebond_staking(u128::MAX, 100, "0x1".to_string());
+        // A second record added directly, since rebonding again would overflow
+        // the staked amount itself
+        let mut again = metrics.staking_activities[0].clone();
+        again.block_number = 101;
+        metrics.staking_activities.push(again);
+        assert_eq!(metrics.get_total_rebonded_amount(), u128::MAX);
+    }
+
+    #[test]
+    fn test_average_staked_amount_saturates() {
+        let start = 1699430400;
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(start));
+        metrics.start_staking(u128::MAX, 100, "0x1".to_string());
+        let mut again = metrics.staking_activities[0].clone();
+        again.block_number = 101;
+        metrics.staking_activities.push(again);
+
+        metrics.clock = FixedClock(start + 86400);
+        assert_eq!(metrics.get_average_staked_amount(), Some(u128::MAX as f64));
+    }
+
+    crate::manager_tests!(StakingMetricsManager, StakingActivityMetrics, total_staking_extrinsics);
 
     #[test]
     fn test_staking_metrics_creation() {
@@ -594,6 +838,18 @@ mod tests {
         assert_eq!(metrics.get_slashed_validators().len(), 1);
     }
 
+    #[test]
+    fn test_slashed_validators_sorted() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+
+        for validator_id in [907, 3, 512, 64, 250, 1, 733] {
+            metrics.add_validator_slash(validator_id, 10);
+        }
+        metrics.add_validator_info(42, 0.05, true);
+
+        assert_eq!(metrics.get_slashed_validators(), vec![1, 3, 64, 250, 512, 733, 907]);
+    }
+
     #[test]
     fn test_reward_claim() {
         let mut manager = StakingMetricsManager::new();
@@ -619,6 +875,25 @@ mod tests {
         assert_eq!(metrics.get_total_staking_extrinsics(), 3);
     }
 
+    #[test]
+    fn test_top_accounts_by_score() {
+        let mut manager = StakingMetricsManager::new();
+        for account_id in [3, 1, 2] {
+            manager.create_metrics(account_id);
+        }
+        
+        manager.metrics.get_mut(&2).unwrap().start_staking(100_000, 1000, "0x01".to_string());
+        
+        let top = manager.top_accounts_by_score(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, 2);
+        assert!(top[0].1 > top[1].1);
+        assert_eq!(top[1].0, 1); // Tie on score broken by account ID
+        
+        assert_eq!(manager.top_accounts_by_score(10).len(), 3);
+        assert!(manager.top_accounts_by_score(0).is_empty());
+    }
+
     #[test]
     fn test_overall_score() {
         let mut manager = StakingMetricsManager::new();
@@ -637,4 +912,46 @@ mod tests {
         assert!(score >= 0.0);
         assert!(safe_validator_score >= 0.0);
     }
+
+    #[test]
+    fn test_safe_validator_commission_grading() {
+        let mut low = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        low.add_validator_info(100, 0.01, true);
+        low.nominate_validators(vec![100], 1000, "0x1".to_string());
+
+        let mut high = StakingActivityMetrics::with_clock(2, FixedClock(1699430400));
+        high.add_validator_info(100, 0.18, true);
+        high.nominate_validators(vec![100], 1000, "0x1".to_string());
+
+        // 1% commission keeps 95% of the quality points, 18% keeps 10%
+        assert!((low.get_validator_safety(100) - 0.95).abs() < 1e-9);
+        assert!((high.get_validator_safety(100) - 0.1).abs() < 1e-9);
+        assert!(low.get_safe_validator_selection_score() > high.get_safe_validator_selection_score());
+        assert!((low.get_safe_validator_selection_score() - (0.95 * 8.0 + 0.4)).abs() < 1e-9);
+
+        high.add_validator_slash(100, 10);
+        assert_eq!(high.get_safe_validator_selection_score(), 0.0);
+    }
+
+    #[test]
+    fn test_safe_validator_contributions_and_diversification() {
+        let mut metrics = StakingActivityMetrics::with_clock(1, FixedClock(1699430400));
+        assert_eq!(metrics.get_safe_validator_selection_score(), 0.0);
+
+        metrics.add_validator_info(100, 0.0, true);
+        metrics.add_validator_info(200, 0.1, true);
+        metrics.add_validator_info(300, 0.05, false);
+        metrics.nominate_validators(vec![300, 100, 200, 400], 1000, "0x1".to_string());
+
+        let contributions = metrics.get_validator_safety_contributions();
+        assert_eq!(contributions.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![100, 200, 300, 400]);
+        assert!((contributions[0].1 - 2.0).abs() < 1e-9);
+        assert!((contributions[1].1 - 1.0).abs() < 1e-9);
+        assert_eq!(contributions[2].1, 0.0); // inactive
+        assert_eq!(contributions[3].1, 0.0); // unknown
+
+        // Two of the five validators needed for full diversification
+        assert!((metrics.get_validator_diversification_bonus() - 0.8).abs() < 1e-9);
+        assert!((metrics.get_safe_validator_selection_score() - 3.8).abs() < 1e-9);
+    }
 }